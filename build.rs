@@ -0,0 +1,423 @@
+//! Generates the problem registry linked into the `euler` binary.
+//!
+//! Every `src/bin/pNNN.rs` is copied into `OUT_DIR` as a module source and
+//! listed in `problems.rs`, which `euler` includes at its crate root with
+//! `common::Problem` in scope. The copies differ from the originals only in
+//! attributes that are allowed at a crate root alone: `#![feature(..)]` is
+//! dropped and `#[macro_use]` on `extern crate` is hoisted into the registry.
+//! As `include!` cannot bring in crate attributes, the binaries that include
+//! the registry must enable the features themselves; the build fails with
+//! the missing ones if they do not.
+//!
+//! Each problem is tagged with the `pkg/` crates it uses, with `file` if it
+//! reads a data file and with `bigint` if it uses big integers. Its metadata
+//! is the `META` defined by `problem!(title: .., ..)`.
+//!
+//! It also passes the compiler version, the profile and the git revision of
+//! the build to the crate, for the host information of JSON results.
+
+#![warn(
+    bad_style,
+    unused,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results
+)]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[path = "src/invocation.rs"]
+mod invocation;
+
+use invocation::skip_meta;
+
+const PROBLEM_DIR: &'static str = "src/bin";
+const PKG_DIR: &'static str = "pkg";
+const BIGINT_CRATE: &'static str = "num_bigint";
+const GIT_DIR: &'static str = ".git";
+const INVOCATION_SOURCE: &'static str = "src/invocation.rs";
+/// Binaries that include the registry.
+const REGISTRY_USERS: &'static [&'static str] = &["src/bin/euler.rs", "src/bin/hash_answers.rs"];
+
+struct ProblemSource {
+    id: u32,
+    name: String,
+    path: PathBuf,
+    tags: Vec<String>,
+    /// The `pkg/` crates used.
+    pkgs: Vec<String>,
+    /// Whether `problem!` is given metadata.
+    has_meta: bool,
+    /// Difficulty rating given in the metadata, in percent.
+    difficulty: Option<u32>,
+    /// Features enabled by `#![feature(..)]`.
+    features: Vec<String>,
+}
+
+fn problem_id(file_name: &str) -> Option<u32> {
+    if file_name.len() != "pNNN.rs".len() || !file_name.starts_with('p') {
+        return None;
+    }
+    if !file_name.ends_with(".rs") {
+        return None;
+    }
+    let digits = &file_name[1..4];
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn problem_sources(dir: &Path) -> io::Result<Vec<ProblemSource>> {
+    let mut sources = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let id = match path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .and_then(problem_id)
+        {
+            Some(id) => id,
+            None => continue,
+        };
+        sources.push(ProblemSource {
+            id: id,
+            name: format!("p{:03}", id),
+            path: path,
+            tags: vec![],
+            pkgs: vec![],
+            has_meta: false,
+            difficulty: None,
+            features: vec![],
+        });
+    }
+    sources.sort_by_key(|s| s.id);
+    Ok(sources)
+}
+
+/// Crate names of the local packages, which double as problem tags.
+fn pkg_names(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    Ok(names)
+}
+
+/// `extern crate` declaration that must also be visible from the crate root.
+struct ExternCrate {
+    decl: String,
+    macro_use: bool,
+}
+
+/// Name bound by `extern crate foo;` or `extern crate foo as bar;`.
+fn extern_crate_name(decl: &str) -> &str {
+    decl.split_whitespace().last().unwrap().trim_matches(';')
+}
+
+/// Whether the answer is followed by a file name in the arguments of
+/// `problem!`, as in `"answer", "file name", solver`.
+fn reads_file(args: &str) -> bool {
+    let mut literals = args.splitn(4, '"');
+    let _ = literals.next();
+    let _ = literals.next(); // the answer
+    match (literals.next(), literals.next()) {
+        (Some(between), Some(_)) => between.trim() == ",",
+        _ => false,
+    }
+}
+
+/// Returns the features enabled by a `#![feature(a, b)]` line.
+fn features(line: &str) -> Vec<String> {
+    let list = line.trim()["#![feature(".len()..].trim_end_matches(")]");
+    list.split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Returns the features enabled at the crate root of `path`.
+fn crate_features(path: &Path) -> io::Result<BTreeSet<String>> {
+    let mut found = BTreeSet::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if line.starts_with("#![feature(") {
+            found.extend(features(&line));
+        }
+    }
+    Ok(found)
+}
+
+/// Panics with the features that the problems need but `bin` does not
+/// enable.
+fn check_features(bin: &str, sources: &[ProblemSource]) {
+    let enabled = crate_features(Path::new(bin)).unwrap();
+    let missing = sources
+        .iter()
+        .flat_map(|src| src.features.iter().map(move |f| (f, &src.name)))
+        .filter(|&(f, _)| !enabled.contains(f))
+        .map(|(f, name)| format!("{} (used by {})", f, name))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        panic!(
+            "{} includes the problem registry but does not enable these features \
+             with #![feature(..)]: {}",
+            bin,
+            missing.join(", ")
+        );
+    }
+}
+
+/// Returns the difficulty rating in the metadata of the arguments of
+/// `problem!`, as in `title: "..", difficulty: N, ..`.
+fn difficulty(args: &str) -> Option<u32> {
+    let meta = &args[..args.len() - skip_meta(args)?.len()];
+    let rating = &meta[meta.find("difficulty:")? + "difficulty:".len()..];
+    rating[..rating.find(',')?].trim().parse().ok()
+}
+
+/// Copies the problem source to `dst` and fills in the tags, `pkg/` crates
+/// and metadata of `src`.
+fn convert_source(
+    src: &mut ProblemSource,
+    dst: &Path,
+    pkgs: &[String],
+    crates: &mut BTreeMap<String, ExternCrate>,
+) -> io::Result<()> {
+    let lines = BufReader::new(File::open(&src.path)?)
+        .lines()
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut out = File::create(dst)?;
+    let mut tags = vec![];
+
+    // Dropped lines are left blank so that panic locations in the copy
+    // match the source.
+    for (i, line) in lines.iter().enumerate() {
+        let next = lines.get(i + 1).map(|s| s.as_str()).unwrap_or("");
+        if line.starts_with("#![feature(") {
+            src.features.extend(features(line));
+            writeln!(out)?;
+            continue;
+        }
+        // `problem!("answer", "file name", solver)`
+        if line.starts_with("problem!(") {
+            // The invocation may span lines up to the one ending with `);`.
+            let end = lines[i..]
+                .iter()
+                .position(|l| l.ends_with(");"))
+                .map_or(i, |n| i + n);
+            let invocation = lines[i..end + 1].join("\n");
+            let args = invocation["problem!(".len()..].trim();
+            src.has_meta = args.starts_with("title:");
+            src.difficulty = difficulty(args);
+            if skip_meta(args).map_or(false, reads_file) {
+                tags.push("file".to_string());
+            }
+        }
+        if line.starts_with("#[macro_use") && next.starts_with("extern crate ") {
+            let name = extern_crate_name(next).to_string();
+            if pkgs.contains(&name) {
+                src.pkgs.push(name.clone());
+            }
+            let _ = crates.insert(
+                name,
+                ExternCrate {
+                    decl: next.to_string(),
+                    macro_use: true,
+                },
+            );
+            writeln!(out)?;
+            continue;
+        }
+        let test_only = i > 0 && lines[i - 1].starts_with("#[cfg(test)]");
+        if line.starts_with("extern crate ") && !test_only {
+            let name = extern_crate_name(line).to_string();
+            if pkgs.contains(&name) && !src.pkgs.contains(&name) {
+                src.pkgs.push(name.clone());
+            }
+            if name == BIGINT_CRATE {
+                tags.push("bigint".to_string());
+            }
+            let _ = crates.entry(name).or_insert(ExternCrate {
+                decl: line.to_string(),
+                macro_use: false,
+            });
+        }
+        writeln!(out, "{}", line)?;
+    }
+
+    src.pkgs.sort();
+    tags.extend(src.pkgs.iter().cloned());
+    tags.sort();
+    src.tags = tags;
+    Ok(())
+}
+
+fn write_registry(
+    out: &mut File,
+    sources: &[ProblemSource],
+    module_dir: &Path,
+    crates: &BTreeMap<String, ExternCrate>,
+) -> io::Result<()> {
+    writeln!(out, "// Generated by build.rs. Do not edit.")?;
+    writeln!(out)?;
+
+    for (name, krate) in crates {
+        // `euler` declares `common` itself.
+        if name == "common" {
+            continue;
+        }
+        writeln!(out, "#[cfg(not(test))]")?;
+        if krate.macro_use {
+            writeln!(out, "#[macro_use]")?;
+        }
+        writeln!(out, "{}", krate.decl)?;
+    }
+    writeln!(out)?;
+
+    for src in sources {
+        let path = module_dir.join(format!("{}.rs", src.name));
+        writeln!(out, "#[cfg(not(test))]")?;
+        writeln!(out, "#[path = {:?}]", path.to_str().unwrap())?;
+        writeln!(out, "mod {};", src.name)?;
+    }
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "/// All problems linked into this binary, ordered by id."
+    )?;
+    writeln!(out, "#[cfg(not(test))]")?;
+    writeln!(out, "pub static PROBLEMS: &'static [Problem] = &[")?;
+    for src in sources {
+        let meta = if src.has_meta {
+            format!("&{}::META", src.name)
+        } else {
+            "&common::Meta::NONE".to_string()
+        };
+        writeln!(
+            out,
+            "    Problem {{ id: {}, solver: {}::solver, meta: {}, tags: &{:?}, crates: &{:?} }},",
+            src.id, src.name, meta, src.tags, src.pkgs
+        )?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "/// Problems are not linked into `euler` when testing."
+    )?;
+    writeln!(out, "#[cfg(test)]")?;
+    writeln!(out, "pub static PROBLEMS: &'static [Problem] = &[];")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "/// Difficulty ratings of the problems, which tests check without them."
+    )?;
+    writeln!(out, "#[cfg(test)]")?;
+    writeln!(out, "#[allow(dead_code)]")?;
+    writeln!(
+        out,
+        "pub static DIFFICULTIES: &'static [(u32, Option<u32>)] = &["
+    )?;
+    for src in sources {
+        writeln!(out, "    ({}, {:?}),", src.id, src.difficulty)?;
+    }
+    writeln!(out, "];")?;
+
+    Ok(())
+}
+
+/// Returns the trimmed standard output of `program args`, if it succeeds.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+fn emit_build_info() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = command_output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=EULER_RUSTC_VERSION={}", version);
+    println!(
+        "cargo:rustc-env=EULER_PROFILE={}",
+        env::var("PROFILE").unwrap()
+    );
+    let revision = command_output("git", &["rev-parse", "HEAD"]).unwrap_or_default();
+    println!("cargo:rustc-env=EULER_GIT_REVISION={}", revision);
+    // Cargo reruns the script on every build for a missing file, so only
+    // existing ones are watched.
+    for path in revision_files(Path::new(GIT_DIR)) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
+
+/// Returns the files whose changes move `HEAD`: `HEAD` itself, which changes
+/// on checkout, and the ref it points to, which changes on commit, or
+/// `packed-refs` if the ref is packed.
+fn revision_files(git_dir: &Path) -> Vec<PathBuf> {
+    let head = git_dir.join("HEAD");
+    let mut content = String::new();
+    if File::open(&head)
+        .and_then(|mut f| f.read_to_string(&mut content))
+        .is_err()
+    {
+        return vec![];
+    }
+    let mut files = vec![head];
+    if content.starts_with("ref:") {
+        let loose = git_dir.join(content["ref:".len()..].trim());
+        let packed = git_dir.join("packed-refs");
+        if loose.is_file() {
+            files.push(loose);
+        } else if packed.is_file() {
+            files.push(packed);
+        }
+    }
+    files
+}
+
+fn main() {
+    emit_build_info();
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let module_dir = out_dir.join("problems");
+    fs::create_dir_all(&module_dir).unwrap();
+
+    println!("cargo:rerun-if-changed={}", PROBLEM_DIR);
+    println!("cargo:rerun-if-changed={}", INVOCATION_SOURCE);
+    println!("cargo:rerun-if-changed={}", PKG_DIR);
+    let mut sources = problem_sources(Path::new(PROBLEM_DIR)).unwrap();
+    let pkgs = pkg_names(Path::new(PKG_DIR)).unwrap();
+
+    let mut crates = BTreeMap::new();
+    for src in &mut sources {
+        println!("cargo:rerun-if-changed={}", src.path.display());
+        let dst = module_dir.join(format!("{}.rs", src.name));
+        convert_source(src, &dst, &pkgs, &mut crates).unwrap();
+    }
+
+    for bin in REGISTRY_USERS {
+        println!("cargo:rerun-if-changed={}", bin);
+        check_features(bin, &sources);
+    }
+
+    let mut out = File::create(out_dir.join("problems.rs")).unwrap();
+    write_registry(&mut out, &sources, &module_dir, &crates).unwrap();
+}
@@ -0,0 +1,321 @@
+//! Expected answers, kept either as plaintext or as salted SHA-256 hashes so
+//! that the sources do not spoil them.
+
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_rational::Ratio;
+use resource::sha256_hex;
+use serde::{Serialize, Serializer};
+use serde_json::{self, Value};
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+use time;
+
+const HASH_PREFIX: &'static str = "sha256:";
+
+/// Expected answer of a problem whose answer is not known yet, as written by
+/// `euler new`. Its results are unchecked rather than wrong.
+pub const UNKNOWN_ANSWER: &'static str = "?";
+
+/// Relative error allowed in float answers, besides their rounding.
+const FLOAT_TOLERANCE: f64 = 1e-9;
+
+/// Hashes `answer` into the form accepted as an expected answer:
+/// `sha256:SALT:HEX`, where `HEX` is the digest of `SALT` followed by the
+/// answer.
+pub fn hash_answer(answer: &str, salt: &str) -> String {
+    assert!(!salt.contains(':'), "salt must not contain ':'");
+    let digest = sha256_hex(format!("{}{}", salt, answer).as_bytes());
+    format!("{}{}:{}", HASH_PREFIX, salt, digest)
+}
+
+/// Returns a new salt for `hash_answer`, differing between `context`s and
+/// between calls.
+pub fn new_salt(context: &str) -> String {
+    let seed = format!("{}:{}:{}", context, process::id(), time::precise_time_ns());
+    sha256_hex(seed.as_bytes())[..16].to_string()
+}
+
+/// Returns true if `expected` is a hashed answer rather than plaintext.
+pub fn is_hashed_answer(expected: &str) -> bool {
+    expected.starts_with(HASH_PREFIX)
+}
+
+/// Types a solver may return as its answer.
+///
+/// A plaintext expected answer is parsed with `FromStr` and compared with
+/// `is`, which is `PartialEq` unless the type says otherwise, so that e.g.
+/// `+5` and `5` are the same `u8`. A hashed one is compared with the
+/// `Display` form. The output shows the `Serialize` form if it is a number
+/// or a string, and the `Display` form otherwise.
+pub trait AnswerType: fmt::Display + FromStr + PartialEq + Serialize + 'static {
+    /// Returns true if `self` is the plaintext answer `expected`.
+    fn is(&self, expected: &str) -> bool {
+        expected
+            .parse::<Self>()
+            .map_or(false, |expected| expected == *self)
+    }
+
+    /// Whether an answer of this type is matched by its exact `Display`
+    /// form, so that it can be hashed.
+    fn is_exact() -> bool {
+        true
+    }
+}
+
+macro_rules! exact_answer_types {
+    ($($ty:ty),*) => {
+        $(impl AnswerType for $ty {})*
+    };
+}
+
+exact_answer_types!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+exact_answer_types!(String, BigUint, BigInt);
+
+impl<T> AnswerType for Ratio<T> where
+    T: Clone + Integer + fmt::Display + FromStr + Serialize + 'static
+{
+}
+
+macro_rules! float_answer_types {
+    ($($ty:ty),*) => {
+        $(impl AnswerType for $ty {
+            /// Matches if `self` rounds to `expected` at the precision
+            /// `expected` is written in, or is within a relative error of
+            /// `FLOAT_TOLERANCE` of it.
+            fn is(&self, expected: &str) -> bool {
+                let value = match expected.parse::<$ty>() {
+                    Ok(value) => value as f64,
+                    Err(_) => return false,
+                };
+                let rounding = match expected.find('.') {
+                    Some(i) if !expected.contains(|c| c == 'e' || c == 'E') => {
+                        0.5 * 10f64.powi(-((expected.len() - i - 1) as i32))
+                    }
+                    _ => 0.0,
+                };
+                let tolerance = rounding.max(FLOAT_TOLERANCE * value.abs());
+                (*self as f64 - value).abs() <= tolerance
+            }
+
+            fn is_exact() -> bool {
+                false
+            }
+        })*
+    };
+}
+
+float_answer_types!(f32, f64);
+
+/// Checks `answer` against a plaintext or hashed expected answer.
+pub fn answer_matches<T: AnswerType>(expected: &str, answer: &T) -> bool {
+    if !is_hashed_answer(expected) {
+        return answer.is(expected);
+    }
+    hash_matches(expected, &answer.to_string())
+}
+
+/// Returns true if the hashed answer `hashed` was made from `answer`.
+fn hash_matches(hashed: &str, answer: &str) -> bool {
+    let mut parts = hashed[HASH_PREFIX.len()..].splitn(2, ':');
+    match (parts.next(), parts.next()) {
+        (Some(salt), Some(digest)) => {
+            sha256_hex(format!("{}{}", salt, answer).as_bytes()) == digest
+        }
+        _ => false,
+    }
+}
+
+/// Returns the `Display` form of the plaintext answer `expected` read as a
+/// `T`, which a hashed answer must be made from, or `None` if it does not
+/// parse or `T` is not matched exactly.
+pub fn display_answer<T: AnswerType>(expected: &str) -> Option<String> {
+    if !T::is_exact() {
+        return None;
+    }
+    expected.parse::<T>().ok().map(|value| value.to_string())
+}
+
+/// An answer of any `AnswerType`, kept in its JSON form so that numbers stay
+/// numbers in the output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answer(Value);
+
+impl Answer {
+    pub fn new<T: Serialize>(value: &T) -> serde_json::Result<Answer> {
+        Ok(Answer(serde_json::to_value(value)?))
+    }
+
+    /// Returns the answer a solver gave. Big integers and ratios serialize
+    /// as their digits or parts, so they are kept in their `Display` form.
+    pub fn of<T: AnswerType>(value: &T) -> serde_json::Result<Answer> {
+        match serde_json::to_value(value)? {
+            number @ Value::Number(_) => Ok(Answer(number)),
+            string @ Value::String(_) => Ok(Answer(string)),
+            _ => Ok(Answer(Value::String(value.to_string()))),
+        }
+    }
+
+    pub fn is_hashed(&self) -> bool {
+        match self.0 {
+            Value::String(ref s) => is_hashed_answer(s),
+            _ => false,
+        }
+    }
+
+    /// Returns the answer hashed with `salt`, or a copy if it is hashed
+    /// already.
+    pub fn hashed(&self, salt: &str) -> Answer {
+        if self.is_hashed() {
+            return self.clone();
+        }
+        Answer(Value::String(hash_answer(&self.to_string(), salt)))
+    }
+
+    /// Returns whether `self` and `other` are the same answer, comparing a
+    /// hashed one with the plaintext by hashing it, or `None` if both are
+    /// hashed and cannot be told apart.
+    pub fn same_as(&self, other: &Answer) -> Option<bool> {
+        match (self.is_hashed(), other.is_hashed()) {
+            (false, false) => Some(self.to_string() == other.to_string()),
+            (true, false) => Some(hash_matches(&self.to_string(), &other.to_string())),
+            (false, true) => Some(hash_matches(&other.to_string(), &self.to_string())),
+            (true, true) if self == other => Some(true),
+            (true, true) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Value::String(ref s) => write!(f, "{}", s),
+            ref value => write!(f, "{}", value),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Answer {
+    fn from(s: &'a str) -> Answer {
+        Answer(Value::String(s.to_string()))
+    }
+}
+
+/// Stand-in for an answer that is not to be shown.
+#[derive(Debug, Clone, Copy)]
+pub struct Hidden;
+
+impl fmt::Display for Hidden {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(hidden)")
+    }
+}
+
+impl Serialize for Hidden {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{answer_matches, display_answer, hash_answer, is_hashed_answer, Answer};
+    use num_bigint::BigUint;
+    use num_rational::Ratio;
+    use serde_json;
+
+    #[test]
+    fn plaintext() {
+        assert!(!is_hashed_answer("12345678"));
+        assert!(answer_matches("12345678", &"12345678".to_string()));
+        assert!(!answer_matches("12345678", &"12345679".to_string()));
+    }
+
+    #[test]
+    fn typed() {
+        assert!(answer_matches("12345678", &12345678u32));
+        assert!(answer_matches("-59231", &-59231i32));
+        assert!(answer_matches("+5", &5u8));
+        assert!(!answer_matches("-59231", &59231i32));
+        assert!(!answer_matches("abc", &0u32));
+        assert!(answer_matches("0.5", &0.5f64));
+    }
+
+    #[test]
+    fn float_tolerance() {
+        assert!(answer_matches("0.464399", &0.46439878f64));
+        assert!(!answer_matches("0.464399", &0.4643978f64));
+        assert!(answer_matches("1e20", &(1e20 + 1e9)));
+        assert!(!answer_matches("1e20", &1.001e20));
+        assert!(answer_matches("2.5", &2.5f32));
+        assert_eq!(None, display_answer::<f64>("0.5"));
+    }
+
+    #[test]
+    fn big_answers() {
+        let big = "1267650600228229401496703205376"
+            .parse::<BigUint>()
+            .unwrap();
+        assert!(answer_matches("1267650600228229401496703205376", &big));
+        let hashed = hash_answer(
+            &display_answer::<BigUint>("01267650600228229401496703205376").unwrap(),
+            "salt",
+        );
+        assert!(answer_matches(&hashed, &big));
+        assert_eq!(
+            "\"1267650600228229401496703205376\"",
+            serde_json::to_string(&Answer::of(&big).unwrap()).unwrap()
+        );
+
+        let ratio = Ratio::new(3u32, 7);
+        assert!(answer_matches("6/14", &ratio));
+        assert_eq!(
+            "\"3/7\"",
+            serde_json::to_string(&Answer::of(&ratio).unwrap()).unwrap()
+        );
+        assert_eq!(
+            "42",
+            serde_json::to_string(&Answer::of(&42u64).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn hashed() {
+        let expected = hash_answer("12345678", "0123abcd");
+        assert!(expected.starts_with("sha256:0123abcd:"));
+        assert!(is_hashed_answer(&expected));
+        assert!(answer_matches(&expected, &12345678u32));
+        assert!(answer_matches(&expected, &"12345678".to_string()));
+        assert!(!answer_matches(&expected, &12345679u32));
+        assert_ne!(expected, hash_answer("12345678", "0123abce"));
+    }
+
+    #[test]
+    fn hashed_display_form() {
+        assert_eq!(Some("5".to_string()), display_answer::<u8>("+5"));
+        assert_eq!(None, display_answer::<u32>("-1"));
+
+        let expected = hash_answer(&display_answer::<u8>("+5").unwrap(), "0123abcd");
+        assert!(answer_matches(&expected, &5u8));
+        assert!(!answer_matches(&hash_answer("+5", "0123abcd"), &5u8));
+    }
+
+    #[test]
+    fn malformed_hash() {
+        assert!(!answer_matches("sha256:", &String::new()));
+        assert!(!answer_matches("sha256:salt", &"salt".to_string()));
+    }
+
+    #[test]
+    fn answer_json() {
+        let number = Answer::new(&-59231i32).unwrap();
+        assert_eq!("-59231", number.to_string());
+        assert_eq!("-59231", serde_json::to_string(&number).unwrap());
+
+        let string = Answer::new(&"0123").unwrap();
+        assert_eq!("0123", string.to_string());
+        assert_eq!(r#""0123""#, serde_json::to_string(&string).unwrap());
+        assert_eq!(string, serde_json::from_str(r#""0123""#).unwrap());
+    }
+}
@@ -0,0 +1,260 @@
+//! Named snapshots of results for spotting regressions between runs.
+
+use record::Record;
+use std::fmt;
+use std::path::PathBuf;
+use {is_hashed_answer, Host};
+
+const BASELINE_DIR: &'static str = "./.cache/baselines";
+
+/// Slowdowns to below this time are too noisy to count as regressions.
+const NOISE_FLOOR_NSEC: u64 = 1_000_000;
+
+/// Default limit of `current time / baseline time` before a problem counts
+/// as regressed.
+pub const DEFAULT_REGRESSION_RATIO: f64 = 1.5;
+
+/// Returns where the baseline `name` is kept: `name` itself if it looks like
+/// a path, or a JSON file under the cache otherwise.
+pub fn baseline_path(name: &str) -> PathBuf {
+    if name.contains('/') || name.ends_with(".json") {
+        PathBuf::from(name)
+    } else {
+        PathBuf::from(BASELINE_DIR).join(format!("{}.json", name))
+    }
+}
+
+/// How a problem got worse compared to the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// It takes more than the allowed ratio of its baseline time.
+    Slower { baseline: u64, current: u64 },
+    /// It gives a different answer. The baseline answer is hashed if it was
+    /// saved by this version.
+    Answer { baseline: String, current: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub id: u32,
+    pub change: Change,
+}
+
+fn secs(nsec: u64) -> f64 {
+    nsec as f64 / 1e9
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.change {
+            Change::Slower { baseline, current } => write!(
+                f,
+                "p{:03}: {:.6}s -> {:.6}s ({:.2}x slower)",
+                self.id,
+                secs(baseline),
+                secs(current),
+                current as f64 / baseline as f64
+            ),
+            Change::Answer {
+                ref baseline,
+                ref current,
+            } if is_hashed_answer(baseline) => {
+                write!(f, "p{:03}: answer changed to {}", self.id, current)
+            }
+            Change::Answer {
+                ref baseline,
+                ref current,
+            } => write!(
+                f,
+                "p{:03}: answer changed from {} to {}",
+                self.id, baseline, current
+            ),
+        }
+    }
+}
+
+/// Finds problems in `current` that got slower than `ratio` times their
+/// baseline or changed their answer. Problems missing from either side are
+/// ignored, and so are answers that cannot be compared.
+pub fn find_regressions(baseline: &[Record], current: &[Record], ratio: f64) -> Vec<Regression> {
+    let mut regressions = vec![];
+    for cur in current {
+        let base = match baseline.iter().find(|r| r.id == cur.id) {
+            Some(base) => base,
+            None => continue,
+        };
+
+        if let (&Some(ref b), &Some(ref c)) = (&base.answer, &cur.answer) {
+            if b.same_as(c) == Some(false) {
+                regressions.push(Regression {
+                    id: cur.id,
+                    change: Change::Answer {
+                        baseline: b.to_string(),
+                        current: c.to_string(),
+                    },
+                });
+            }
+        }
+
+        let timed = base.status.is_some() && cur.status.is_some();
+        if timed && cur.time >= NOISE_FLOOR_NSEC && cur.time as f64 > base.time as f64 * ratio {
+            regressions.push(Regression {
+                id: cur.id,
+                change: Change::Slower {
+                    baseline: base.time,
+                    current: cur.time,
+                },
+            });
+        }
+    }
+    regressions
+}
+
+fn host(records: &[Record]) -> Option<&Host> {
+    records
+        .iter()
+        .filter_map(|r| r.run.as_ref())
+        .map(|r| &r.host)
+        .next()
+}
+
+/// Describes how the host or build of `current` differs from that of
+/// `baseline`, which makes their times hard to compare. Returns `None` if they
+/// are the same or either is unknown, as in results of schema version 1.
+pub fn host_difference(baseline: &[Record], current: &[Record]) -> Option<String> {
+    let (base, cur) = match (host(baseline), host(current)) {
+        (Some(base), Some(cur)) => (base, cur),
+        _ => return None,
+    };
+    let unknown = "unknown".to_string();
+    let fields = [
+        (
+            "cpu",
+            base.cpu.clone().unwrap_or(unknown.clone()),
+            cur.cpu.clone().unwrap_or(unknown),
+        ),
+        ("cores", base.cores.to_string(), cur.cores.to_string()),
+        ("rustc", base.rustc.clone(), cur.rustc.clone()),
+        ("profile", base.profile.clone(), cur.profile.clone()),
+    ];
+    let diffs = fields
+        .iter()
+        .filter(|&&(_, ref b, ref c)| b != c)
+        .map(|&(name, ref b, ref c)| format!("{} {} -> {}", name, b, c))
+        .collect::<Vec<_>>();
+    if diffs.is_empty() {
+        None
+    } else {
+        Some(diffs.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{baseline_path, find_regressions, host_difference, Change, Regression};
+    use record::Record;
+    use std::path::Path;
+    use {Host, RunInfo, Status};
+
+    fn record(id: u32, time: u64, answer: &str) -> Record {
+        Record {
+            status: Some(Status::Ok),
+            time: time,
+            answer: Some(answer.into()),
+            ..Record::new(id)
+        }
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            Path::new("./.cache/baselines/main.json"),
+            baseline_path("main")
+        );
+        assert_eq!(Path::new("out/main.json"), baseline_path("out/main.json"));
+        assert_eq!(Path::new("main.json"), baseline_path("main.json"));
+    }
+
+    #[test]
+    fn regressions() {
+        let ms = 1_000_000;
+        let baseline = [
+            record(1, 10 * ms, "1"),
+            record(2, 10 * ms, "2"),
+            record(3, 10, "3"),
+            record(4, 10 * ms, "4"),
+        ];
+        let current = [
+            record(1, 14 * ms, "1"),
+            record(2, 16 * ms, "2"),
+            record(3, 100, "3"),
+            record(4, 10 * ms, "5"),
+            record(5, 100 * ms, "5"),
+        ];
+        assert_eq!(
+            vec![
+                Regression {
+                    id: 2,
+                    change: Change::Slower {
+                        baseline: 10 * ms,
+                        current: 16 * ms,
+                    },
+                },
+                Regression {
+                    id: 4,
+                    change: Change::Answer {
+                        baseline: "4".to_string(),
+                        current: "5".to_string(),
+                    },
+                },
+            ],
+            find_regressions(&baseline, &current, 1.5)
+        );
+    }
+
+    #[test]
+    fn hashed_answers() {
+        let hashed = |id, answer: &str, salt: &str| Record {
+            answer: record(id, 10, answer).answer.map(|a| a.hashed(salt)),
+            ..record(id, 10, "")
+        };
+        let baseline = [hashed(1, "1", "salt"), hashed(2, "2", "salt")];
+        let current = [record(1, 10, "1"), record(2, 10, "3")];
+        let regressions = find_regressions(&baseline, &current, 1.5);
+        assert_eq!(1, regressions.len());
+        assert_eq!(2, regressions[0].id);
+        assert_eq!("p002: answer changed to 3", regressions[0].to_string());
+
+        // Hashes with different salts cannot be compared.
+        let other = [hashed(1, "1", "pepper")];
+        assert!(find_regressions(&baseline, &other, 1.5).is_empty());
+    }
+
+    #[test]
+    fn host() {
+        let with_host = |profile: &str| {
+            let mut r = record(1, 10, "1");
+            r.run = Some(RunInfo {
+                started: "2018-08-01T00:00:00Z".to_string(),
+                finished: "2018-08-01T00:00:01Z".to_string(),
+                host: Host {
+                    cpu: None,
+                    cores: 4,
+                    rustc: "rustc 1.30.0-nightly".to_string(),
+                    profile: profile.to_string(),
+                },
+                revision: None,
+            });
+            r
+        };
+        let legacy = [record(1, 10, "1")];
+        let debug = [with_host("debug")];
+        let release = [with_host("release")];
+        assert_eq!(None, host_difference(&legacy, &release));
+        assert_eq!(None, host_difference(&release, &release));
+        assert_eq!(
+            Some("profile debug -> release".to_string()),
+            host_difference(&debug, &release)
+        );
+    }
+}
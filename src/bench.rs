@@ -0,0 +1,162 @@
+//! Timing of solver runs.
+
+use getopts::{Matches, Options};
+use time;
+use {Error, Result};
+
+/// Times a single call of `f` in nanoseconds.
+pub fn bench<T, F: FnOnce() -> T>(f: F) -> (u64, T) {
+    let start_time = time::precise_time_ns();
+    let result = f();
+    let end_time = time::precise_time_ns();
+    let nsec = end_time - start_time;
+    (nsec, result)
+}
+
+const DEFAULT_WARMUP: u32 = 3;
+
+/// Number of discarded and measured runs in benchmark mode.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: u32,
+    pub runs: u32,
+}
+
+impl BenchConfig {
+    /// Registers the `--bench` and `--warmup` options.
+    pub fn define_opts(opts: &mut Options) {
+        let _ = opts.optopt(
+            "",
+            "bench",
+            "Run N measured iterations and report statistics",
+            "N",
+        );
+        let _ = opts.optopt(
+            "",
+            "warmup",
+            &format!(
+                "Run N unmeasured iterations before --bench (default {})",
+                DEFAULT_WARMUP
+            ),
+            "N",
+        );
+    }
+
+    /// Returns the configuration if `--bench` is given.
+    pub fn from_matches(matches: &Matches) -> Result<Option<BenchConfig>> {
+        let runs = match matches.opt_str("bench") {
+            Some(s) => parse_count("bench", &s)?,
+            None => return Ok(None),
+        };
+        if runs == 0 {
+            return Err(Error::BadCli("--bench needs at least one run".to_string()));
+        }
+        let warmup = match matches.opt_str("warmup") {
+            Some(s) => parse_count("warmup", &s)?,
+            None => DEFAULT_WARMUP,
+        };
+        Ok(Some(BenchConfig {
+            warmup: warmup,
+            runs: runs,
+        }))
+    }
+}
+
+fn parse_count(opt: &str, s: &str) -> Result<u32> {
+    s.parse()
+        .map_err(|_| Error::BadCli(format!("--{} needs a number: {}", opt, s)))
+}
+
+/// Summary statistics of the measured runs, in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub warmup: u32,
+    pub runs: u32,
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub stddev: u64,
+    /// Runs outside 1.5 interquartile ranges from the quartiles.
+    pub outliers: u32,
+}
+
+impl BenchStats {
+    /// Summarizes the durations of the measured runs.
+    ///
+    /// `samples` must not be empty.
+    pub fn new(warmup: u32, samples: &[u64]) -> BenchStats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len() as f64;
+
+        let mean = sorted.iter().map(|&x| x as f64).sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted
+                .iter()
+                .map(|&x| (x as f64 - mean) * (x as f64 - mean))
+                .sum::<f64>()
+                / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let iqr = q3 - q1;
+        let outliers = sorted
+            .iter()
+            .filter(|&&x| (x as f64) < q1 - 1.5 * iqr || (x as f64) > q3 + 1.5 * iqr)
+            .count();
+
+        BenchStats {
+            warmup: warmup,
+            runs: sorted.len() as u32,
+            min: sorted[0],
+            median: percentile(&sorted, 0.5).round() as u64,
+            mean: mean.round() as u64,
+            stddev: variance.sqrt().round() as u64,
+            outliers: outliers as u32,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted samples.
+fn percentile(sorted: &[u64], p: f64) -> f64 {
+    let pos = p * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    let frac = pos - lo as f64;
+    sorted[lo] as f64 + (sorted[hi] as f64 - sorted[lo] as f64) * frac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BenchStats;
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::new(0, &[42]);
+        assert_eq!(42, stats.min);
+        assert_eq!(42, stats.median);
+        assert_eq!(0, stats.stddev);
+        assert_eq!(0, stats.outliers);
+    }
+
+    #[test]
+    fn summary() {
+        let stats = BenchStats::new(3, &[30, 10, 20, 40]);
+        assert_eq!(3, stats.warmup);
+        assert_eq!(4, stats.runs);
+        assert_eq!(10, stats.min);
+        assert_eq!(25, stats.median);
+        assert_eq!(25, stats.mean);
+        assert_eq!(13, stats.stddev);
+        assert_eq!(0, stats.outliers);
+    }
+
+    #[test]
+    fn outliers() {
+        let stats = BenchStats::new(0, &[10, 11, 10, 12, 11, 10, 100]);
+        assert_eq!(11, stats.median);
+        assert_eq!(1, stats.outliers);
+    }
+}
@@ -6,114 +6,1672 @@
     unused_qualifications,
     unused_results
 )]
+// Required by the problem modules included from the registry; build.rs fails
+// with the features missing here.
+#![feature(no_panic_pow, slice_patterns)]
 
+#[cfg_attr(not(test), macro_use(problem))]
 extern crate common;
-#[macro_use]
-extern crate failure;
-extern crate glob;
+extern crate getopts;
+extern crate libc;
 extern crate serde_json;
 
-use common::SolverResult;
-use glob::Paths;
+use common::{
+    Answer, BenchConfig, Budgets, CaughtPanic, Change, Error, ErrorInfo, ErrorKind, ErrorOutput,
+    FileInfo, FileState, Format, HttpFetcher, Problem, ProblemInfo, Record, Resources, Result,
+    Selection, SolverResult, Status,
+};
+use getopts::{Matches, Options};
+use std::collections::BTreeMap;
 use std::env;
+use std::fs::{File, OpenOptions};
 use std::io;
 use std::io::prelude::*;
 use std::os::unix::process::ExitStatusExt;
-use std::path::Path;
-use std::process;
-use std::process::Command;
-use std::str;
+use std::path::{Path, PathBuf};
+use std::process::{self, Child, Command, ExitStatus, Stdio};
+use std::str::{self, FromStr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
-const PROBLEM_EXE_PAT: &'static str = "p[0-9][0-9][0-9]";
+include!(concat!(env!("OUT_DIR"), "/problems.rs"));
 
-type Result<T> = std::result::Result<T, failure::Error>;
+/// Where `euler new` puts new solvers by default, relative to the root of
+/// the repository.
+const PROBLEM_DIR: &'static str = "src/bin";
+/// Where the registry copies the solvers to, keeping their line numbers.
+const MODULE_DIR: &'static str = concat!(env!("OUT_DIR"), "/problems");
 
-fn problem_paths(dir_path: &Path) -> Result<Paths> {
-    let pat = dir_path.join(PROBLEM_EXE_PAT);
-    match pat.to_str() {
-        Some(x) => Ok(glob::glob(x)?),
-        None => bail!("path contains non-utf8 character"),
+const BYTES_PER_MIB: u64 = 1024 * 1024;
+const POLL_INTERVAL_MSEC: u64 = 10;
+
+/// Message printed by the default allocation error handler before aborting.
+const ALLOC_ERROR_MSG: &'static str = "memory allocation of";
+
+struct Config {
+    resources: Resources,
+    resource_dir: Option<String>,
+    bench: Option<BenchConfig>,
+    /// Runs each problem in a child process when set.
+    isolation: Option<Isolation>,
+    jobs: usize,
+    /// Held around each timed run with `--exclusive-timing`, so that the
+    /// workers never time two solvers at once.
+    timing_lock: Option<Mutex<()>>,
+    /// Prints a report in this format instead of the colored lines.
+    format: Option<Format>,
+    /// Shows answers in the output.
+    reveal: bool,
+    /// Reports right answers over their budget as `SLOW`.
+    budgets: Budgets,
+    /// Records the phases of the solvers.
+    phases: bool,
+}
+
+/// What to do with the results of this run besides printing them.
+struct Baseline {
+    /// Results of an earlier run to compare against.
+    compare: Option<Vec<Record>>,
+    ratio: f64,
+    save: Option<PathBuf>,
+    /// Where to write an HTML report of the run.
+    html: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy)]
+struct Isolation {
+    timeout: Option<Duration>,
+    memory_limit: Option<u64>,
+}
+
+enum Outcome {
+    Solved(SolverResult<Answer>),
+    /// Killed by the isolating parent after the time given, for a timeout or
+    /// running out of memory.
+    Killed(ErrorInfo, u64),
+    /// Panicked after the time given.
+    Panicked(CaughtPanic, u64),
+    Failed(ErrorInfo),
+}
+
+/// Returns the status of a problem killed with `error`.
+fn killed_status(error: &ErrorInfo) -> Status {
+    match error.kind {
+        ErrorKind::Timeout => Status::Timeout,
+        _ => Status::OutOfMemory,
+    }
+}
+
+fn nsec(d: Duration) -> u64 {
+    d.as_secs() * 1_000_000_000 + d.subsec_nanos() as u64
+}
+
+fn run_problem(problem: &Problem, config: &Config) -> Result<SolverResult<Answer>> {
+    let solver = (problem.solver)();
+    let mut result = match config.bench {
+        Some(bench) => solver.bench_with(&config.resources, bench)?,
+        None => solver.solve_with(&config.resources)?,
+    };
+    result.id = Some(problem.id);
+    if config.jobs > 1 {
+        // The peak RSS is that of the process, including the other runs.
+        result.memory = None;
+    }
+    Ok(result)
+}
+
+/// Runs a problem in this process, catching its panic.
+fn run_caught(problem: &Problem, config: &Config) -> Result<Outcome> {
+    let start = Instant::now();
+    match common::catch_panic(|| run_problem(problem, config)) {
+        Ok(result) => result.map(Outcome::Solved),
+        Err(mut panic) => {
+            // Point at the solver source rather than its copy in the registry.
+            panic.location = panic.location.map(|l| l.replacen(MODULE_DIR, "src/bin", 1));
+            Ok(Outcome::Panicked(panic, nsec(start.elapsed())))
+        }
     }
 }
 
-fn run_problem(path: &Path) -> Result<SolverResult<String>> {
-    let proc_out = Command::new(path).arg("--json").output()?;
+fn set_memory_limit(bytes: u64) -> io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
 
-    if !proc_out.stderr.is_empty() {
-        let _ = match str::from_utf8(&proc_out.stderr) {
+fn child_args(problem: &Problem, config: &Config, isolation: Isolation) -> Vec<String> {
+    let mut args = vec!["--child".to_string(), problem.id.to_string()];
+    if let Some(ref dir) = config.resource_dir {
+        args.push("--resource-dir".to_string());
+        args.push(dir.clone());
+    }
+    if config.resources.allows_unlisted() {
+        args.push("--allow-unlisted".to_string());
+    }
+    if config.phases {
+        args.push("--verbose".to_string());
+    }
+    if let Some(bench) = config.bench {
+        args.push("--bench".to_string());
+        args.push(bench.runs.to_string());
+        args.push("--warmup".to_string());
+        args.push(bench.warmup.to_string());
+    }
+    if let Some(bytes) = isolation.memory_limit {
+        args.push("--memory-limit".to_string());
+        args.push((bytes / BYTES_PER_MIB).to_string());
+    }
+    args
+}
+
+/// Waits for the child to exit, killing it once `timeout` has elapsed.
+fn wait_child(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if let Some(timeout) = timeout {
+            if start.elapsed() >= timeout {
+                child.kill()?;
+                let _ = child.wait()?;
+                return Ok(None);
+            }
+        }
+        thread::sleep(Duration::from_millis(POLL_INTERVAL_MSEC));
+    }
+}
+
+fn read_all<R: Read + Send + 'static>(mut reader: R) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = vec![];
+        let _ = reader.read_to_end(&mut buf);
+        buf
+    })
+}
+
+fn run_isolated(problem: &Problem, config: &Config, isolation: Isolation) -> Result<Outcome> {
+    let start = Instant::now();
+    let mut child = Command::new(env::current_exe()?)
+        .args(&child_args(problem, config, isolation))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = read_all(child.stdout.take().unwrap());
+    let stderr = read_all(child.stderr.take().unwrap());
+
+    let status = wait_child(&mut child, isolation.timeout)?;
+    let elapsed = nsec(start.elapsed());
+    let stdout = stdout.join().unwrap();
+    let stderr = stderr.join().unwrap();
+
+    if !stderr.is_empty() {
+        let _ = match str::from_utf8(&stderr) {
             Ok(s) => writeln!(&mut io::stderr(), "{}", s.trim()),
-            Err(e) => writeln!(&mut io::stderr(), "{:?}: {}", proc_out.stderr, e),
+            Err(e) => writeln!(&mut io::stderr(), "{:?}: {}", stderr, e),
         };
     }
 
-    match proc_out.status.code() {
+    child_outcome(status, &stdout, &stderr, elapsed, isolation)
+}
+
+/// Returns the outcome of a child that exited with `status`, or was killed
+/// for a timeout if it is `None`, from what it printed.
+fn child_outcome(
+    status: Option<ExitStatus>,
+    stdout: &[u8],
+    stderr: &[u8],
+    elapsed: u64,
+    isolation: Isolation,
+) -> Result<Outcome> {
+    let status = match status {
+        Some(status) => status,
+        None => return Ok(Outcome::Killed(Error::Timeout.info(), elapsed)),
+    };
+
+    match status.code() {
         Some(0) | Some(1) => {} // expected
-        Some(st) => bail!("child process exit with {}", st),
-        None => bail!(
-            "child process exit with signal {}",
-            proc_out.status.signal().unwrap()
-        ),
+        Some(st) => {
+            // The child prints the error it failed with, including panics,
+            // unless it died before catching them.
+            let error = match serde_json::from_slice::<ErrorOutput>(stdout) {
+                Ok(output) => output.error,
+                Err(_) => {
+                    ErrorInfo::new(ErrorKind::Panic, format!("child process exit with {}", st))
+                }
+            };
+            if let Some(location) = error.location {
+                let panic = CaughtPanic {
+                    message: error.message,
+                    location: Some(location),
+                };
+                return Ok(Outcome::Panicked(panic, elapsed));
+            }
+            return Ok(Outcome::Failed(error));
+        }
+        None => {
+            let out_of_memory = isolation.memory_limit.is_some()
+                && String::from_utf8_lossy(stderr).contains(ALLOC_ERROR_MSG);
+            if out_of_memory {
+                return Ok(Outcome::Killed(Error::OutOfMemory.info(), elapsed));
+            }
+            return Ok(Outcome::Failed(ErrorInfo::new(
+                ErrorKind::Panic,
+                format!(
+                    "child process exit with signal {}",
+                    status.signal().unwrap()
+                ),
+            )));
+        }
     }
 
-    let result = serde_json::from_reader(&mut &proc_out.stdout[..])?;
-    Ok(result)
+    let result = serde_json::from_slice(stdout)?;
+    Ok(Outcome::Solved(result))
 }
 
-fn run() -> Result<bool> {
-    let dir_path = {
-        let mut path = env::current_exe()?;
-        let _ = path.pop();
-        path
+fn solve(problem: &Problem, config: &Config) -> Outcome {
+    let _guard = config.timing_lock.as_ref().map(|lock| {
+        // Fetch the data file outside of the lock; a failure is reported
+        // again by the solver itself.
+        if let Some(file_name) = (problem.solver)().file_name() {
+            let _ = config.resources.open(file_name);
+        }
+        lock.lock().unwrap_or_else(|e| e.into_inner())
+    });
+
+    let outcome = match config.isolation {
+        Some(isolation) => run_isolated(problem, config, isolation),
+        None => run_caught(problem, config),
     };
+    match outcome {
+        Ok(Outcome::Solved(mut r)) => {
+            r.apply_budget(config.budgets.budget(problem.id));
+            Outcome::Solved(r)
+        }
+        Ok(outcome) => outcome,
+        Err(e) => Outcome::Failed(e.info()),
+    }
+}
+
+fn list(problems: &[Problem]) {
+    let mut out = io::stdout();
+    for problem in problems {
+        let _ = writeln!(&mut out, "{}", problem.name());
+    }
+}
+
+/// Formats the difficulty rating of a problem as `25%`, or `-` if unrated.
+fn difficulty(info: &ProblemInfo) -> String {
+    info.difficulty
+        .map_or_else(|| "-".to_string(), |rating| format!("{}%", rating))
+}
+
+/// Prints the metadata of the problems as a table.
+fn list_info<W: Write>(out: &mut W, infos: &[ProblemInfo]) -> io::Result<()> {
+    let width = infos
+        .iter()
+        .map(|info| info.title.len())
+        .chain(Some("TITLE".len()))
+        .max()
+        .unwrap();
+    writeln!(
+        out,
+        "{:4}  {:>4}  {:width$}  TAGS",
+        "ID",
+        "DIFF",
+        "TITLE",
+        width = width
+    )?;
+    for info in infos {
+        let difficulty = difficulty(info);
+        if info.tags.is_empty() {
+            writeln!(out, "{:4}  {:>4}  {}", info.name, difficulty, info.title)?;
+        } else {
+            writeln!(
+                out,
+                "{:4}  {:>4}  {:width$}  {}",
+                info.name,
+                difficulty,
+                info.title,
+                info.tags.join(" "),
+                width = width
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the metadata of a problem as `key value` lines.
+fn print_info<W: Write>(out: &mut W, info: &ProblemInfo) -> io::Result<()> {
+    fn or_none(s: String) -> String {
+        if s.is_empty() {
+            "-".to_string()
+        } else {
+            s
+        }
+    }
+
+    let params = info
+        .params
+        .iter()
+        .map(|(name, default)| format!("{} = {}", name, default))
+        .collect::<Vec<_>>();
+    writeln!(out, "{}  {}", info.name, info.title)?;
+    writeln!(out, "url         {}", info.url)?;
+    writeln!(out, "difficulty  {}", difficulty(info))?;
+    writeln!(out, "tags        {}", or_none(info.tags.join(", ")))?;
+    writeln!(out, "files       {}", or_none(info.files.join(", ")))?;
+    writeln!(out, "crates      {}", or_none(info.crates.join(", ")))?;
+    writeln!(out, "params      {}", or_none(params.join(", ")))?;
+    Ok(())
+}
+
+/// Runs `euler new ID [FILE]`: writes the skeleton of a solver for problem
+/// `ID` that reads the data file `FILE` if given into `dir`, or into
+/// `src/bin` of the current directory.
+fn new_problem(args: &[String], title: Option<&str>, dir: Option<&str>) -> Result<PathBuf> {
+    let (id, file_name) = match args {
+        [ref id] => (id, None),
+        [ref id, ref file_name] => (id, Some(file_name.as_str())),
+        _ => return Err(Error::BadCli("usage: new ID [FILE]".to_string())),
+    };
+    let id = match id.parse::<u32>() {
+        Ok(id) if id > 0 && id < 1000 => id,
+        _ => return Err(Error::BadCli(format!("invalid problem id: {}", id))),
+    };
+
+    let dir = Path::new(dir.unwrap_or(PROBLEM_DIR));
+    if !dir.is_dir() {
+        return Err(Error::BadCli(format!(
+            "{}: no such directory (run from the repository root or pass --dir)",
+            dir.display()
+        )));
+    }
+    let path = dir.join(format!("p{:03}.rs", id));
+    let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(Error::BadCli(format!("{} already exists", path.display())))
+        }
+        Err(e) => return Err(Error::Io(e)),
+    };
+    file.write_all(common::problem_source(id, title, file_name).as_bytes())?;
+    Ok(path)
+}
+
+/// Runs `euler gen ID|FILE`: returns a generated input for problem `ID`, or
+/// for the data file `FILE`.
+fn generate(args: &[String], size: Option<usize>, seed: u64) -> Result<String> {
+    let name = match args {
+        [ref name] => name,
+        _ => return Err(Error::BadCli("usage: gen ID|FILE".to_string())),
+    };
+    let file_name = match name.trim_start_matches('p').parse::<u32>() {
+        Ok(id) => match PROBLEMS.iter().find(|p| p.id == id) {
+            Some(problem) => match problem.info().files.first() {
+                Some(file_name) => file_name.to_string(),
+                None => {
+                    return Err(Error::BadCli(format!(
+                        "{} reads no data file",
+                        problem.name()
+                    )))
+                }
+            },
+            None => return Err(Error::BadCli(format!("no such problem: {}", name))),
+        },
+        Err(_) => name.clone(),
+    };
+    common::generate(&file_name, size, seed).ok_or_else(|| {
+        Error::BadCli(format!(
+            "no generator for {} (supported: {})",
+            file_name,
+            common::generated_files().join(", ")
+        ))
+    })
+}
+
+/// Runs `euler list` or `euler info` on the selected problems.
+fn show_info(command: &str, problems: &[Problem], json: bool) -> Result<()> {
+    let infos = problems.iter().map(|p| p.info()).collect::<Vec<_>>();
     let mut out = io::stdout();
+    if command == "info" {
+        if infos.len() != 1 {
+            return Err(Error::BadCli("info needs a single problem id".to_string()));
+        }
+        if json {
+            serde_json::to_writer_pretty(&mut out, &infos[0])?;
+            writeln!(out)?;
+        } else {
+            print_info(&mut out, &infos[0])?;
+        }
+    } else if json {
+        serde_json::to_writer_pretty(&mut out, &infos)?;
+        writeln!(out)?;
+    } else {
+        list_info(&mut out, &infos)?;
+    }
+    Ok(())
+}
+
+/// Returns the data files read by the registered problems, with the problems
+/// reading each one, and the partial files left in the resource directory.
+fn cache_files(resources: &Resources) -> Result<BTreeMap<String, Vec<String>>> {
+    let mut files = BTreeMap::new();
+    for problem in PROBLEMS {
+        for file_name in problem.info().files {
+            files
+                .entry(file_name.to_string())
+                .or_insert_with(Vec::new)
+                .push(problem.name());
+        }
+    }
+    for file_name in resources.partial_files()? {
+        let _ = files.entry(file_name).or_insert_with(Vec::new);
+    }
+    Ok(files)
+}
+
+fn state_name(state: FileState) -> &'static str {
+    match state {
+        FileState::Verified => "verified",
+        FileState::Unlisted => "unlisted",
+        FileState::Mismatch => "mismatch",
+        FileState::Partial => "partial",
+        FileState::Missing => "missing",
+    }
+}
+
+/// Prints the state of the data files as a table.
+fn list_cache<W: Write>(out: &mut W, files: &[(FileInfo, Vec<String>)]) -> io::Result<()> {
+    let width = files
+        .iter()
+        .map(|&(ref info, _)| info.name.len())
+        .chain(Some("FILE".len()))
+        .max()
+        .unwrap();
+    writeln!(
+        out,
+        "{:width$}  {:>10}  {:8}  {:12}  PROBLEMS",
+        "FILE",
+        "SIZE",
+        "STATE",
+        "DIGEST",
+        width = width
+    )?;
+    for &(ref info, ref problems) in files {
+        writeln!(
+            out,
+            "{:width$}  {:>10}  {:8}  {:12}  {}",
+            info.name,
+            info.size.map_or("-".to_string(), common::format_bytes),
+            state_name(info.state),
+            info.digest.as_ref().map_or("-", |d| &d[..12]),
+            if problems.is_empty() {
+                "-".to_string()
+            } else {
+                problems.join(" ")
+            },
+            width = width
+        )?;
+    }
+    Ok(())
+}
+
+/// Runs `euler cache COMMAND` on the resource directory and returns the exit
+/// code.
+fn cache(command: Option<&str>, resources: &Resources, json: bool) -> Result<i32> {
+    let files = cache_files(resources)?;
+    let mut out = io::stdout();
+    let mut code = 0;
+    match command {
+        Some("list") => {
+            let mut infos = vec![];
+            for (file_name, problems) in files {
+                infos.push((resources.inspect(&file_name)?, problems));
+            }
+            if json {
+                let mut values = vec![];
+                for &(ref info, ref problems) in &infos {
+                    let mut value = serde_json::to_value(info)?;
+                    value["problems"] = serde_json::to_value(problems)?;
+                    values.push(value);
+                }
+                serde_json::to_writer_pretty(&mut out, &values)?;
+                writeln!(out)?;
+            } else {
+                list_cache(&mut out, &infos)?;
+            }
+        }
+        Some("verify") => {
+            let mut checked = 0;
+            for file_name in files.keys() {
+                let info = resources.inspect(file_name)?;
+                match info.state {
+                    FileState::Missing => continue,
+                    FileState::Verified => {}
+                    FileState::Unlisted if !resources.rejects_unlisted() => {}
+                    state => {
+                        writeln!(out, "{}: {}", file_name, state_name(state))?;
+                        code = ErrorKind::ChecksumMismatch.exit_code();
+                    }
+                }
+                checked += 1;
+            }
+            writeln!(
+                out,
+                "{} files checked in {}",
+                checked,
+                resources.dir().display()
+            )?;
+        }
+        Some("clean") => {
+            for file_name in files.keys() {
+                let state = resources.inspect(file_name)?.state;
+                if state == FileState::Mismatch || state == FileState::Partial {
+                    resources.remove(file_name)?;
+                    writeln!(out, "removed {} ({})", file_name, state_name(state))?;
+                }
+            }
+        }
+        Some("prefetch") => {
+            let mut downloader =
+                Resources::new(resources.dir().to_path_buf(), HttpFetcher::project_euler()?);
+            downloader.set_allow_unlisted(resources.allows_unlisted());
+            for (file_name, problems) in files {
+                if problems.is_empty() {
+                    continue;
+                }
+                let before = downloader.inspect(&file_name)?.state;
+                match downloader.open(&file_name) {
+                    Ok(_) if before == FileState::Missing => {
+                        writeln!(out, "fetched {}", file_name)?
+                    }
+                    Ok(_) => writeln!(out, "kept {}", file_name)?,
+                    Err(e) => {
+                        let program = env::args().next().unwrap();
+                        let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                        if code == 0 {
+                            code = e.kind().exit_code();
+                        }
+                    }
+                }
+            }
+        }
+        _ => {
+            return Err(Error::BadCli(
+                "usage: cache list|verify|clean|prefetch".to_string(),
+            ))
+        }
+    }
+    Ok(code)
+}
+
+/// Solves the problems with `solve` on `jobs` worker threads and passes each
+/// outcome to `report` in problem order.
+fn solve_all<S, F>(problems: Arc<Vec<Problem>>, jobs: usize, solve: Arc<S>, mut report: F)
+where
+    S: Fn(&Problem) -> Outcome + Send + Sync + 'static,
+    F: FnMut(&Problem, Outcome),
+{
+    let next = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = mpsc::channel();
+
+    for _ in 0..jobs {
+        let problems = problems.clone();
+        let solve = solve.clone();
+        let next = next.clone();
+        let tx = tx.clone();
+        let _ = thread::spawn(move || loop {
+            let i = next.fetch_add(1, Ordering::SeqCst);
+            if i >= problems.len() {
+                break;
+            }
+            let outcome = solve(&problems[i]);
+            if tx.send((i, outcome)).is_err() {
+                break;
+            }
+        });
+    }
+    drop(tx);
+
+    let mut pending = BTreeMap::new();
+    let mut next_report = 0;
+    for (i, outcome) in rx {
+        let _ = pending.insert(i, outcome);
+        while let Some(outcome) = pending.remove(&next_report) {
+            report(&problems[next_report], outcome);
+            next_report += 1;
+        }
+    }
+
+    // Solvers run under `catch_panic`, but a worker thread that died anyway
+    // left its problem unreported.
+    for i in next_report..problems.len() {
+        let outcome = match pending.remove(&i) {
+            Some(outcome) => outcome,
+            None => Outcome::Failed(Error::Panic("solver panicked".to_string()).info()),
+        };
+        report(&problems[i], outcome);
+    }
+}
 
+fn record(problem: &Problem, outcome: &Outcome) -> Record {
+    let mut record = Record::new(problem.id);
+    match *outcome {
+        Outcome::Solved(ref r) => {
+            record.status = Some(r.status());
+            record.time = r.time;
+            record.memory = r.memory;
+            record.phases = r.phases.clone();
+            record.answer = Some(r.answer.clone());
+            record.expected = r.expected;
+            record.error = r.error().map(|e| e.info());
+            record.run = r.run.clone();
+        }
+        Outcome::Killed(ref error, time) => {
+            record.status = Some(killed_status(error));
+            record.time = time;
+            record.error = Some(error.clone());
+        }
+        Outcome::Panicked(ref panic, time) => {
+            record.status = Some(Status::Panicked);
+            record.time = time;
+            record.error = Some(panic.info());
+        }
+        Outcome::Failed(ref e) => record.error = Some(e.clone()),
+    }
+    record
+}
+
+/// Returns the exit code `euler` fails with because of `record`, if any.
+fn exit_code(record: &Record) -> Option<i32> {
+    if let Some(ref error) = record.error {
+        return Some(error.kind.exit_code());
+    }
+    match record.status {
+        Some(Status::Ok) | Some(Status::Unchecked) => None,
+        Some(Status::Ng) => Some(ErrorKind::WrongAnswer.exit_code()),
+        Some(Status::Timeout) => Some(ErrorKind::Timeout.exit_code()),
+        Some(Status::OutOfMemory) => Some(ErrorKind::OutOfMemory.exit_code()),
+        Some(Status::Slow) => Some(ErrorKind::Slow.exit_code()),
+        Some(Status::Panicked) | None => Some(ErrorKind::Panic.exit_code()),
+    }
+}
+
+/// Prints the regressions against the baseline and fails if there are any.
+fn check_regressions<W: Write>(
+    out: &mut W,
+    baseline: &[Record],
+    records: &[Record],
+    ratio: f64,
+    reveal: bool,
+) -> Result<()> {
+    if let Some(difference) = common::host_difference(baseline, records) {
+        let _ = writeln!(
+            out,
+            "note: the baseline was taken on another host or build: {}",
+            difference
+        );
+    }
+    let regressions = common::find_regressions(baseline, records, ratio);
+    if regressions.is_empty() {
+        return Ok(());
+    }
+    let error = Error::Regression(regressions.len());
+    let _ = writeln!(out, "{}:", error);
+    for r in &regressions {
+        let _ = match r.change {
+            Change::Answer { .. } if !reveal => writeln!(out, "  p{:03}: answer changed", r.id),
+            _ => writeln!(out, "  {}", r),
+        };
+    }
+    Err(error)
+}
+
+/// Solves the problems and returns the exit code: that of the first problem
+/// that failed, or of the regressions against the baseline.
+fn run(problems: Vec<Problem>, config: Config, baseline: &Baseline) -> i32 {
+    let mut out = io::stdout();
+    let format = config.format;
+    let reveal = config.reveal;
+    let budgets = config.budgets.clone();
+    let crates = problems
+        .iter()
+        .map(|p| (p.id, p.crates))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut records = vec![];
     let mut is_ok = true;
     let mut num_prob = 0;
     let mut total_time = 0;
-    for path in problem_paths(&dir_path)? {
-        let path = path?;
-        let program = path.file_name().unwrap().to_string_lossy().to_string();
+    let mut failures = vec![];
+    let jobs = config.jobs;
+    let solve_one = Arc::new(move |problem: &Problem| solve(problem, &config));
+    solve_all(Arc::new(problems), jobs, solve_one, |problem, outcome| {
+        let program = problem.name();
+        records.push(record(problem, &outcome));
+        if format.is_some() {
+            is_ok &= records.last().unwrap().is_ok();
+            return;
+        }
 
-        match run_problem(&path) {
-            Ok(ref r) => {
+        let limits = Some(budgets.limits(problem.id));
+        match outcome {
+            Outcome::Solved(ref r) => {
                 num_prob += 1;
                 total_time += r.time;
-                is_ok &= r.is_ok;
-                let _ = r.print_pretty(&program, true);
+                is_ok &= r.is_ok && !r.slow;
+                if !r.is_ok {
+                    failures.push(Status::Ng.label());
+                } else if r.slow {
+                    failures.push(Status::Slow.label());
+                }
+                let _ = if reveal {
+                    r.print_pretty(&program, limits)
+                } else {
+                    r.without_answer().print_pretty(&program, limits)
+                };
             }
-            Err(e) => {
+            Outcome::Killed(ref error, time) => {
                 is_ok = false;
-                let _ = writeln!(&mut out, "{}: {:?}", program, e);
+                let status = killed_status(error);
+                failures.push(status.label());
+                let _ = common::print_status(&program, status, time, "", limits);
+            }
+            Outcome::Panicked(ref panic, time) => {
+                is_ok = false;
+                failures.push(Status::Panicked.label());
+                let message = panic.to_string();
+                let _ = common::print_status(&program, Status::Panicked, time, &message, limits);
+            }
+            Outcome::Failed(ref error) => {
+                is_ok = false;
+                failures.push(error.kind.label());
+                let _ = common::print_error(&program, error, 0, limits);
             }
         }
-    }
+    });
+
+    if let Some(format) = format {
+        if let Err(e) = common::write_report(&mut out, format, &records, reveal) {
+            let _ = writeln!(&mut io::stderr(), "failed to write report: {}", e);
+        }
+    } else if num_prob > 0 {
+        // The statuses come first, then the kinds of errors as they failed.
+        let mut labels = [
+            Status::Ng,
+            Status::Timeout,
+            Status::OutOfMemory,
+            Status::Panicked,
+            Status::Slow,
+        ]
+        .iter()
+        .map(|st| st.label())
+        .collect::<Vec<_>>();
+        for &label in &failures {
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        let summary = labels
+            .iter()
+            .map(|&label| (label, failures.iter().filter(|&&f| f == label).count()))
+            .filter(|&(_, n)| n > 0)
+            .map(|(label, n)| format!("{} {}", n, label))
+            .collect::<Vec<_>>()
+            .join(", ");
 
-    if num_prob > 0 {
         let r = SolverResult {
+            schema: common::SCHEMA_VERSION,
+            id: None,
+            expected: None,
+            run: None,
             time: total_time / num_prob,
-            answer: "".to_string(),
+            answer: summary.clone(),
             is_ok: is_ok,
+            unchecked: false,
+            slow: false,
+            bench: None,
+            memory: None,
+            phases: vec![],
         };
-        let _ = r.print_pretty(" AVG", true);
+        let _ = r.print_pretty(" AVG", Some(budgets.global_limits()));
 
         let r = SolverResult {
+            schema: common::SCHEMA_VERSION,
+            id: None,
+            expected: None,
+            run: None,
             time: total_time,
-            answer: "".to_string(),
+            answer: summary,
             is_ok: is_ok,
+            unchecked: false,
+            slow: false,
+            bench: None,
+            memory: None,
+            phases: vec![],
         };
-        let _ = r.print_pretty(" SUM", false);
+        let _ = r.print_pretty(" SUM", None);
+
+        // The first of the problems with the largest peak.
+        let peak = records
+            .iter()
+            .filter_map(|r| r.memory.map(|m| (m.peak(), r.id)))
+            .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        if let Some((bytes, id)) = peak {
+            let _ = writeln!(
+                &mut out,
+                " MAX memory {} (p{:03})",
+                common::format_bytes(bytes),
+                id
+            );
+        }
     }
 
-    Ok(is_ok)
+    let mut code = records.iter().filter_map(exit_code).next().unwrap_or(0);
+    if let Some(ref base) = baseline.compare {
+        let checked = if format.is_some() {
+            check_regressions(&mut io::stderr(), base, &records, baseline.ratio, reveal)
+        } else {
+            check_regressions(&mut out, base, &records, baseline.ratio, reveal)
+        };
+        if let Err(e) = checked {
+            if code == 0 {
+                code = e.kind().exit_code();
+            }
+        }
+    }
+    if let Some(ref path) = baseline.html {
+        let compare = baseline.compare.as_ref().map(|records| &records[..]);
+        let result = File::create(path)
+            .map_err(Error::from)
+            .and_then(|mut file| common::write_html(&mut file, &records, &crates, compare));
+        if let Err(e) = result {
+            let _ = writeln!(
+                &mut io::stderr(),
+                "failed to write HTML report to {}: {}",
+                path.display(),
+                e
+            );
+            if code == 0 {
+                code = e.kind().exit_code();
+            }
+        }
+    }
+    if let Some(ref path) = baseline.save {
+        if let Err(e) = common::save_records(path, &records) {
+            let _ = writeln!(
+                &mut io::stderr(),
+                "failed to save baseline to {}: {}",
+                path.display(),
+                e
+            );
+            if code == 0 {
+                code = e.kind().exit_code();
+            }
+        }
+    }
+
+    if let Err(e) = common::update_records(common::LAST_RESULTS, &records) {
+        let _ = writeln!(
+            &mut io::stderr(),
+            "failed to save results to {}: {}",
+            common::LAST_RESULTS,
+            e
+        );
+    }
+
+    code
+}
+
+/// Runs a single problem on behalf of an isolating parent and prints its
+/// result as JSON.
+fn run_child(id: &str, config: &Config, memory_limit: Option<u64>) -> Result<bool> {
+    let problem = match PROBLEMS.iter().find(|p| p.id.to_string() == id) {
+        Some(problem) => problem,
+        None => return Err(Error::BadCli(format!("no such problem: {}", id))),
+    };
+    if let Some(bytes) = memory_limit {
+        set_memory_limit(bytes)?;
+    }
+
+    let result = match run_caught(problem, config)? {
+        Outcome::Solved(result) => result,
+        Outcome::Panicked(panic, _) => {
+            let mut output = ErrorOutput::new(panic.info());
+            output.id = Some(problem.id);
+            serde_json::to_writer(io::stdout(), &output)?;
+            process::exit(ErrorKind::Panic.exit_code());
+        }
+        _ => unreachable!(),
+    };
+    result.print_json(&mut io::stdout())?;
+    Ok(result.is_ok)
+}
+
+fn parse_opt<T: FromStr>(opt: &str, s: &str) -> Result<T> {
+    s.parse()
+        .map_err(|_| Error::BadCli(format!("--{} needs a number: {}", opt, s)))
+}
+
+fn parse_config(matches: &Matches) -> Result<Config> {
+    let resource_dir = matches.opt_str("resource-dir");
+    let mut resources = match resource_dir {
+        Some(ref dir) => Resources::offline(dir.clone()),
+        None => Resources::from_env()?,
+    };
+    if matches.opt_present("allow-unlisted") {
+        resources.set_allow_unlisted(true);
+    }
+
+    let timeout = match matches.opt_str("timeout") {
+        Some(s) => {
+            let secs = parse_opt::<f64>("timeout", &s)?;
+            if !(secs > 0.0) {
+                return Err(Error::BadCli("--timeout must be positive".to_string()));
+            }
+            Some(Duration::new(
+                secs.trunc() as u64,
+                (secs.fract() * 1e9) as u32,
+            ))
+        }
+        None => None,
+    };
+    let memory_limit = match matches.opt_str("memory-limit") {
+        Some(s) => Some(parse_opt::<u64>("memory-limit", &s)? * BYTES_PER_MIB),
+        None => None,
+    };
+    let isolation = if matches.opt_present("isolate") || timeout.is_some() || memory_limit.is_some()
+    {
+        Some(Isolation {
+            timeout: timeout,
+            memory_limit: memory_limit,
+        })
+    } else {
+        None
+    };
+
+    let jobs = match matches.opt_str("jobs") {
+        Some(s) => parse_opt::<usize>("jobs", &s)?,
+        None => 1,
+    };
+    if jobs == 0 {
+        return Err(Error::BadCli(
+            "--jobs needs at least one worker".to_string(),
+        ));
+    }
+    // The counters are shared by the threads of the process.
+    if cfg!(feature = "count-alloc") && jobs > 1 && isolation.is_none() {
+        return Err(Error::BadCli(
+            "counting allocations with --jobs above 1 needs --isolate".to_string(),
+        ));
+    }
+
+    let format = match matches.opt_str("format") {
+        Some(s) => Some(s.parse()?),
+        None => None,
+    };
+
+    Ok(Config {
+        resources: resources,
+        resource_dir: resource_dir,
+        bench: BenchConfig::from_matches(matches)?,
+        isolation: isolation,
+        jobs: jobs,
+        timing_lock: if matches.opt_present("exclusive-timing") {
+            Some(Mutex::new(()))
+        } else {
+            None
+        },
+        format: format,
+        reveal: matches.opt_present("reveal"),
+        budgets: Budgets::load()?,
+        phases: matches.opt_present("verbose") || format == Some(Format::Json),
+    })
+}
+
+fn parse_baseline(matches: &Matches) -> Result<Baseline> {
+    let compare = match matches.opt_str("compare") {
+        Some(name) => {
+            let path = common::baseline_path(&name);
+            match common::load_records(&path)? {
+                Some(records) => Some(records),
+                None => return Err(Error::BadCli(format!("no baseline at {}", path.display()))),
+            }
+        }
+        None => None,
+    };
+    let ratio = match matches.opt_str("regression-ratio") {
+        Some(s) => parse_opt::<f64>("regression-ratio", &s)?,
+        None => common::DEFAULT_REGRESSION_RATIO,
+    };
+    if !(ratio >= 1.0) {
+        return Err(Error::BadCli(
+            "--regression-ratio must be at least 1".to_string(),
+        ));
+    }
+
+    Ok(Baseline {
+        compare: compare,
+        ratio: ratio,
+        save: matches
+            .opt_str("save-baseline")
+            .map(|name| common::baseline_path(&name)),
+        html: matches.opt_str("html").map(PathBuf::from),
+    })
+}
+
+/// Returns the `--size` and `--seed` of `euler gen`.
+fn parse_gen_options(matches: &Matches) -> Result<(Option<usize>, u64)> {
+    let size = match matches.opt_str("size") {
+        Some(s) => Some(parse_opt::<usize>("size", &s)?),
+        None => None,
+    };
+    let seed = match matches.opt_str("seed") {
+        Some(s) => parse_opt::<u64>("seed", &s)?,
+        None => 1,
+    };
+    Ok((size, seed))
+}
+
+fn parse_selection(matches: &Matches, ids: &[String]) -> Result<Selection> {
+    let mut selection = Selection::new();
+    for s in ids {
+        selection.add_ids(s)?;
+    }
+    for tag in matches.opt_strs("tag") {
+        selection.add_tag(tag);
+    }
+    if let Some(s) = matches.opt_str("difficulty") {
+        selection.set_difficulty(&s)?;
+    }
+    if let Some(s) = matches.opt_str("slower-than") {
+        selection.set_slower_than(common::parse_duration(&s)?);
+    }
+    if matches.opt_present("rerun-failed") {
+        selection.set_failed_only();
+    }
+    Ok(selection)
+}
+
+fn select(selection: &Selection) -> Result<Vec<Problem>> {
+    let records = if selection.needs_records() {
+        match common::load_records(common::LAST_RESULTS)? {
+            Some(records) => records,
+            None => {
+                return Err(Error::BadCli(format!(
+                    "no saved results in {}",
+                    common::LAST_RESULTS
+                )))
+            }
+        }
+    } else {
+        vec![]
+    };
+    let unrated = selection.unrated(PROBLEMS);
+    if !unrated.is_empty() {
+        let names = unrated.iter().map(|p| p.name()).collect::<Vec<_>>();
+        let _ = writeln!(
+            &mut io::stderr(),
+            "note: {} problems without a difficulty rating are not selected: {}",
+            names.len(),
+            names.join(" ")
+        );
+    }
+    selection.select(PROBLEMS, &records)
 }
 
 fn main() {
-    match run() {
-        Ok(true) => process::exit(0),
-        Ok(false) => process::exit(1),
+    let args = env::args().collect::<Vec<_>>();
+    let program = &args[0];
+
+    let mut opts = Options::new();
+    let _ = opts.optflag("l", "list", "List the names of the selected problems");
+    let _ = opts.optflag("", "json", "Print `list`, `info` and `cache list` as JSON");
+    let _ = opts.optopt(
+        "",
+        "title",
+        "Title of the problem created by `new`",
+        "TITLE",
+    );
+    let _ = opts.optopt(
+        "",
+        "dir",
+        "Directory `new` writes to (default src/bin of the current directory)",
+        "DIR",
+    );
+    let _ = opts.optmulti(
+        "",
+        "tag",
+        "Select problems tagged TAG, e.g. prime or file (repeat to require several)",
+        "TAG",
+    );
+    let _ = opts.optopt(
+        "",
+        "difficulty",
+        "Select problems rated RANGE percent on Project Euler, e.g. 5 or 10-25",
+        "RANGE",
+    );
+    let _ = opts.optopt(
+        "",
+        "slower-than",
+        "Select problems whose last run took at least TIME, e.g. 1s or 500ms",
+        "TIME",
+    );
+    let _ = opts.optflag(
+        "",
+        "rerun-failed",
+        "Select problems whose last run did not succeed",
+    );
+    let _ = opts.optopt(
+        "",
+        "resource-dir",
+        "Read data files from DIR instead of downloading them",
+        "DIR",
+    );
+    let _ = opts.optflag(
+        "",
+        "allow-unlisted",
+        "Use data files without a digest in the resource manifest",
+    );
+    BenchConfig::define_opts(&mut opts);
+    let _ = opts.optflag("", "isolate", "Run each problem in a child process");
+    let _ = opts.optopt(
+        "",
+        "timeout",
+        "Kill a problem after SECS seconds (implies --isolate)",
+        "SECS",
+    );
+    let _ = opts.optopt(
+        "",
+        "memory-limit",
+        "Limit the address space of a problem to MIB mebibytes (implies --isolate)",
+        "MIB",
+    );
+    let _ = opts.optopt("j", "jobs", "Solve N problems concurrently", "N");
+    let _ = opts.optflag(
+        "",
+        "exclusive-timing",
+        "Run one solver at a time so that timings are not distorted",
+    );
+    let _ = opts.optopt(
+        "",
+        "format",
+        "Print one report as junit, tap, csv, markdown or json",
+        "FMT",
+    );
+    let _ = opts.optflag("", "reveal", "Show answers in the output");
+    let _ = opts.optflag("v", "verbose", "Show the time of each phase of the solvers");
+    let _ = opts.optopt(
+        "",
+        "html",
+        "Also write an HTML report with a chart of the times to PATH (deltas need --compare)",
+        "PATH",
+    );
+    let _ = opts.optopt(
+        "",
+        "color",
+        "Color the output: auto (default), always or never",
+        "WHEN",
+    );
+    let _ = opts.optopt(
+        "",
+        "save-baseline",
+        "Save the results as baseline NAME (a name under .cache, or a path)",
+        "NAME",
+    );
+    let _ = opts.optopt(
+        "",
+        "compare",
+        "Fail if a problem got slower or changed its answer since baseline NAME",
+        "NAME",
+    );
+    let _ = opts.optopt(
+        "",
+        "regression-ratio",
+        &format!(
+            "Allowed slowdown against the baseline (default {})",
+            common::DEFAULT_REGRESSION_RATIO
+        ),
+        "RATIO",
+    );
+    let _ = opts.optopt(
+        "",
+        "size",
+        "Size of the input made by `gen` (default about the real file)",
+        "N",
+    );
+    let _ = opts.optopt(
+        "",
+        "seed",
+        "Seed of the input made by `gen` (default 1)",
+        "S",
+    );
+    let _ = opts.optopt(
+        "",
+        "child",
+        "Run problem ID alone and print JSON (used by --isolate)",
+        "ID",
+    );
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(m) => m,
+        Err(f) => {
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, f);
+            process::exit(ErrorKind::BadCli.exit_code());
+        }
+    };
+
+    if matches.opt_present("h") {
+        let short = format!(
+            "{} [list|info] [ID|FIRST-LAST]...\n       {} new ID [FILE]\n       \
+             {} cache list|verify|clean|prefetch\n       {} gen ID|FILE",
+            opts.short_usage(&program),
+            program,
+            program,
+            program
+        );
+        println!("{}", opts.usage(&short));
+        return;
+    }
+
+    if let Some(when) = matches.opt_str("color") {
+        match when.parse() {
+            Ok(choice) => common::set_color(choice),
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                process::exit(ErrorKind::BadCli.exit_code());
+            }
+        }
+    }
+
+    if matches.free.first().map(|s| s.as_str()) == Some("new") {
+        let title = matches.opt_str("title");
+        let dir = matches.opt_str("dir");
+        let created = new_problem(
+            &matches.free[1..],
+            title.as_ref().map(|s| s.as_str()),
+            dir.as_ref().map(|s| s.as_str()),
+        );
+        match created {
+            Ok(path) => println!("created {}; rebuild euler to register it", path.display()),
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                process::exit(e.kind().exit_code());
+            }
+        }
+        return;
+    }
+
+    if matches.free.first().map(|s| s.as_str()) == Some("gen") {
+        let input = parse_gen_options(&matches)
+            .and_then(|(size, seed)| generate(&matches.free[1..], size, seed));
+        match input {
+            Ok(input) => print!("{}", input),
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                process::exit(e.kind().exit_code());
+            }
+        }
+        return;
+    }
+
+    if matches.free.first().map(|s| s.as_str()) == Some("cache") {
+        let command = matches.free.get(1).map(|s| s.as_str());
+        let json = matches.opt_present("json");
+        match parse_config(&matches).and_then(|config| cache(command, &config.resources, json)) {
+            Ok(0) => {}
+            Ok(code) => process::exit(code),
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                process::exit(e.kind().exit_code());
+            }
+        }
+        return;
+    }
+
+    let (command, ids) = match matches.free.first().map(|s| s.as_str()) {
+        Some("list") | Some("info") => (Some(matches.free[0].clone()), &matches.free[1..]),
+        _ => (None, &matches.free[..]),
+    };
+
+    let problems = match parse_selection(&matches, ids).and_then(|sel| select(&sel)) {
+        Ok(problems) => problems,
+        Err(e) => {
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+            process::exit(e.kind().exit_code());
+        }
+    };
+
+    if matches.opt_present("l") {
+        list(&problems);
+        return;
+    }
+
+    if let Some(command) = command {
+        if let Err(e) = show_info(&command, &problems, matches.opt_present("json")) {
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+            process::exit(e.kind().exit_code());
+        }
+        return;
+    }
+
+    let config = match parse_config(&matches) {
+        Ok(config) => config,
+        Err(e) => {
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+            process::exit(e.kind().exit_code());
+        }
+    };
+
+    if config.phases {
+        common::enable_phases();
+    }
+
+    if let Some(id) = matches.opt_str("child") {
+        let memory_limit = config.isolation.and_then(|iso| iso.memory_limit);
+        match run_child(&id, &config, memory_limit) {
+            Ok(true) => process::exit(0),
+            Ok(false) => process::exit(ErrorKind::WrongAnswer.exit_code()),
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                let mut output = ErrorOutput::new(e.info());
+                output.id = id.parse().ok();
+                let _ = serde_json::to_writer(io::stdout(), &output);
+                process::exit(e.kind().exit_code());
+            }
+        }
+    }
+
+    let baseline = match parse_baseline(&matches) {
+        Ok(baseline) => baseline,
         Err(e) => {
-            let _ = writeln!(&mut io::stderr(), "{:?}", e);
-            process::exit(255);
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+            process::exit(e.kind().exit_code());
+        }
+    };
+
+    let code = run(problems, config, &baseline);
+    if code != 0 {
+        process::exit(code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        child_outcome, record, solve, solve_all, wait_child, Config, Isolation, Outcome,
+        BYTES_PER_MIB, DIFFICULTIES,
+    };
+    use common::{
+        Budgets, ErrorInfo, ErrorKind, ErrorOutput, Meta, Problem, Resources, Solver, Status,
+    };
+    use serde_json;
+    use std::env;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{Command, ExitStatus};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    fn problem(id: u32, solver: fn() -> Solver<'static>) -> Problem {
+        Problem {
+            id: id,
+            solver: solver,
+            meta: &Meta::NONE,
+            tags: &[],
+            crates: &[],
+        }
+    }
+
+    fn slow_solver() -> Solver<'static> {
+        Solver::new("1", || {
+            thread::sleep(Duration::from_millis(100));
+            1u32
+        })
+    }
+
+    fn fast_solver() -> Solver<'static> {
+        Solver::new("2", || 2u32)
+    }
+
+    fn panicking_solver() -> Solver<'static> {
+        Solver::new("3", || -> u32 { panic!("solver failed") })
+    }
+
+    /// Number of `timed_solver` runs in progress.
+    static TIMED_RUNNING: AtomicUsize = AtomicUsize::new(0);
+    /// Number of `timed_solver` runs started while another was in progress.
+    static TIMED_OVERLAPS: AtomicUsize = AtomicUsize::new(0);
+
+    fn timed_solver() -> Solver<'static> {
+        Solver::new("4", || {
+            if TIMED_RUNNING.fetch_add(1, Ordering::SeqCst) > 0 {
+                let _ = TIMED_OVERLAPS.fetch_add(1, Ordering::SeqCst);
+            }
+            thread::sleep(Duration::from_millis(50));
+            let _ = TIMED_RUNNING.fetch_sub(1, Ordering::SeqCst);
+            4u32
+        })
+    }
+
+    fn config(jobs: usize) -> Config {
+        Config {
+            resources: Resources::offline(env::temp_dir()),
+            resource_dir: None,
+            bench: None,
+            isolation: None,
+            jobs: jobs,
+            timing_lock: None,
+            format: None,
+            reveal: false,
+            budgets: Budgets::default(),
+            phases: false,
+        }
+    }
+
+    fn describe(outcome: &Outcome) -> String {
+        match *outcome {
+            Outcome::Solved(ref r) if r.is_ok => "solved".to_string(),
+            Outcome::Solved(_) => "wrong".to_string(),
+            Outcome::Killed(ref e, _) | Outcome::Failed(ref e) => format!("{:?}", e.kind),
+            Outcome::Panicked(ref panic, _) => format!("panicked: {}", panic.message),
+        }
+    }
+
+    /// Solves `problems` and returns the ids in the order they are reported,
+    /// with a description of each outcome.
+    fn solve_ids<S>(problems: Vec<Problem>, jobs: usize, solve: S) -> Vec<(u32, String)>
+    where
+        S: Fn(&Problem) -> Outcome + Send + Sync + 'static,
+    {
+        let mut reported = vec![];
+        solve_all(
+            Arc::new(problems),
+            jobs,
+            Arc::new(solve),
+            |problem, outcome| {
+                reported.push((problem.id, describe(&outcome)));
+            },
+        );
+        reported
+    }
+
+    #[test]
+    fn in_order() {
+        let problems = vec![
+            problem(1, slow_solver),
+            problem(2, fast_solver),
+            problem(3, fast_solver),
+            problem(4, fast_solver),
+        ];
+        let config = config(3);
+        let reported = solve_ids(problems, 3, move |p: &Problem| solve(p, &config));
+        let solved = "solved".to_string();
+        assert_eq!(
+            vec![
+                (1, solved.clone()),
+                (2, solved.clone()),
+                (3, solved.clone()),
+                (4, solved)
+            ],
+            reported
+        );
+    }
+
+    #[test]
+    fn solver_panic() {
+        let problems = vec![problem(1, panicking_solver), problem(2, fast_solver)];
+        let config = config(2);
+        let reported = solve_ids(problems, 2, move |p: &Problem| solve(p, &config));
+        assert_eq!(
+            vec![
+                (1, "panicked: solver failed".to_string()),
+                (2, "solved".to_string())
+            ],
+            reported
+        );
+    }
+
+    #[test]
+    fn exclusive_timing() {
+        let problems = (1..5).map(|id| problem(id, timed_solver)).collect();
+        let mut config = config(2);
+        config.timing_lock = Some(Mutex::new(()));
+        let reported = solve_ids(problems, 2, move |p: &Problem| solve(p, &config));
+        let ids = reported.iter().map(|r| r.0).collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3, 4], ids);
+        assert!(reported.iter().all(|r| r.1 == "solved"));
+        assert_eq!(0, TIMED_OVERLAPS.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn worker_died() {
+        let problems = vec![
+            problem(1, fast_solver),
+            problem(2, fast_solver),
+            problem(3, fast_solver),
+        ];
+        let config = config(2);
+        let reported = solve_ids(problems, 2, move |p: &Problem| {
+            // Outside of `catch_panic`, so the worker thread dies.
+            if p.id == 2 {
+                panic!("worker died");
+            }
+            solve(p, &config)
+        });
+        assert_eq!(
+            vec![
+                (1, "solved".to_string()),
+                (2, format!("{:?}", ErrorKind::Panic)),
+                (3, "solved".to_string())
+            ],
+            reported
+        );
+    }
+
+    #[test]
+    fn difficulties() {
+        assert!(!DIFFICULTIES.is_empty());
+        let unrated = DIFFICULTIES
+            .iter()
+            .filter(|&&(_, difficulty)| difficulty.is_none())
+            .map(|&(id, _)| id)
+            .collect::<Vec<_>>();
+        assert!(unrated.is_empty(), "unrated problems: {:?}", unrated);
+    }
+
+    fn isolation() -> Isolation {
+        Isolation {
+            timeout: Some(Duration::from_secs(1)),
+            memory_limit: Some(64 * BYTES_PER_MIB),
         }
     }
+
+    /// Returns the status of a child that exited with `code`.
+    fn exit(code: i32) -> ExitStatus {
+        ExitStatus::from_raw(code << 8)
+    }
+
+    #[test]
+    fn kill_child() {
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        let timeout = Some(Duration::from_millis(50));
+        assert!(wait_child(&mut child, timeout).unwrap().is_none());
+        assert!(child.try_wait().unwrap().is_some());
+
+        let mut child = Command::new("true").spawn().unwrap();
+        let status = wait_child(&mut child, Some(Duration::from_secs(10))).unwrap();
+        assert!(status.unwrap().success());
+    }
+
+    #[test]
+    fn child_timeout() {
+        match child_outcome(None, b"", b"", 1_000, isolation()).unwrap() {
+            Outcome::Killed(ref error, time) => {
+                assert_eq!(ErrorKind::Timeout, error.kind);
+                assert_eq!(1_000, time);
+            }
+            ref outcome => panic!("unexpected outcome: {}", describe(outcome)),
+        }
+    }
+
+    #[test]
+    fn child_out_of_memory() {
+        // Aborted by the allocation error handler.
+        let aborted = ExitStatus::from_raw(6);
+        let stderr = b"memory allocation of 1073741824 bytes failed\n";
+        match child_outcome(Some(aborted), b"", stderr, 2_000, isolation()).unwrap() {
+            Outcome::Killed(ref error, time) => {
+                assert_eq!(ErrorKind::OutOfMemory, error.kind);
+                assert_eq!(2_000, time);
+            }
+            ref outcome => panic!("unexpected outcome: {}", describe(outcome)),
+        }
+
+        let no_limit = Isolation {
+            memory_limit: None,
+            ..isolation()
+        };
+        let outcome = child_outcome(Some(aborted), b"", stderr, 2_000, no_limit).unwrap();
+        assert_eq!(format!("{:?}", ErrorKind::Panic), describe(&outcome));
+    }
+
+    #[test]
+    fn child_result() {
+        let resources = Resources::offline(env::temp_dir());
+        let result = fast_solver().solve_with(&resources).unwrap();
+        let stdout = serde_json::to_vec(&result).unwrap();
+        let outcome = child_outcome(Some(exit(0)), &stdout, b"", 10, isolation()).unwrap();
+        assert_eq!("solved", describe(&outcome));
+
+        let error = ErrorInfo::new(ErrorKind::Download, "p054_poker.txt: not found");
+        let stdout = serde_json::to_vec(&ErrorOutput::new(error)).unwrap();
+        let code = ErrorKind::Download.exit_code();
+        let outcome = child_outcome(Some(exit(code)), &stdout, b"", 10, isolation()).unwrap();
+        assert_eq!(format!("{:?}", ErrorKind::Download), describe(&outcome));
+
+        let mut error = ErrorInfo::new(ErrorKind::Panic, "index out of bounds");
+        error.location = Some("src/bin/p001.rs:10:5".to_string());
+        let stdout = serde_json::to_vec(&ErrorOutput::new(error)).unwrap();
+        let code = ErrorKind::Panic.exit_code();
+        let outcome = child_outcome(Some(exit(code)), &stdout, b"", 10, isolation()).unwrap();
+        assert_eq!("panicked: index out of bounds", describe(&outcome));
+        let panicked = record(&problem(1, fast_solver), &outcome);
+        assert_eq!(Some(Status::Panicked), panicked.status);
+        assert_eq!(10, panicked.time);
+
+        let outcome = child_outcome(Some(exit(code)), b"", b"", 10, isolation()).unwrap();
+        assert_eq!(format!("{:?}", ErrorKind::Panic), describe(&outcome));
+    }
 }
@@ -0,0 +1,243 @@
+//! Replaces the plaintext expected answers of the problems with salted
+//! hashes, so that reading the sources does not spoil them.
+//!
+//! A hashed answer is compared with the `Display` form of the solver's
+//! answer, so the plaintext is first read as the solver's answer type. The
+//! solvers are linked in from the same registry as `euler`'s.
+
+#![warn(
+    bad_style,
+    unused,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results
+)]
+// Required by the problem modules included from the registry; build.rs fails
+// with the features missing here.
+#![feature(no_panic_pow, slice_patterns)]
+
+#[cfg_attr(not(test), macro_use(problem))]
+extern crate common;
+extern crate getopts;
+
+use common::{Error, ErrorKind, Problem, Result};
+use getopts::Options;
+use std::env;
+use std::fs::{self, File};
+use std::io;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process;
+
+#[path = "../invocation.rs"]
+mod invocation;
+
+use invocation::skip_meta;
+
+include!(concat!(env!("OUT_DIR"), "/problems.rs"));
+
+const PROBLEM_DIR: &'static str = "src/bin";
+const INVOCATION: &'static str = "problem!(";
+
+/// Suffix of a source being written, which is renamed once it is complete.
+const PART_SUFFIX: &'static str = ".part";
+
+fn problem_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_problem = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .map_or(false, |s| {
+                s.len() == "pNNN.rs".len() && s.starts_with('p') && s.ends_with(".rs")
+            });
+        if is_problem {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Returns the offset of the expected answer in the arguments of
+/// `problem!`, after the metadata.
+fn answer_offset(args: &str) -> Option<usize> {
+    let rest = skip_meta(args)?;
+    if rest.starts_with('"') {
+        Some(args.len() - rest.len() + 1)
+    } else {
+        None
+    }
+}
+
+/// Returns the registered problem whose source is at `path`.
+fn find_problem(path: &Path) -> Option<&'static Problem> {
+    let id = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .filter(|s| s.starts_with('p'))
+        .and_then(|s| s[1..].parse::<u32>().ok())?;
+    PROBLEMS.iter().find(|p| p.id == id)
+}
+
+/// Returns the source at `path` with the expected answer in `problem!(..)`
+/// hashed, or `None` if it is already hashed or not known.
+fn convert(path: &Path) -> Result<Option<String>> {
+    let mut source = String::new();
+    let _ = File::open(path)?.read_to_string(&mut source)?;
+
+    let args = match source.find(INVOCATION) {
+        Some(i) => i + INVOCATION.len(),
+        None => return Ok(None),
+    };
+    let start = match answer_offset(&source[args..]) {
+        Some(i) => args + i,
+        None => {
+            let msg = format!("{}: no answer in problem!(..)", path.display());
+            return Err(Error::Parse(msg));
+        }
+    };
+    let len = match source[start..].find('"') {
+        Some(len) => len,
+        None => {
+            let msg = format!("{}: unterminated answer", path.display());
+            return Err(Error::Parse(msg));
+        }
+    };
+    let answer = &source[start..start + len];
+    if common::is_hashed_answer(answer) || answer == common::UNKNOWN_ANSWER {
+        return Ok(None);
+    }
+
+    let problem = match find_problem(path) {
+        Some(problem) => problem,
+        None => {
+            let msg = format!("{}: not a registered problem", path.display());
+            return Err(Error::BadCli(msg));
+        }
+    };
+    let display = match (problem.solver)().display_answer(answer) {
+        Some(display) => display,
+        None => {
+            let msg = format!(
+                "{}: {:?} is not an exact answer of the solver's type",
+                path.display(),
+                answer
+            );
+            return Err(Error::Parse(msg));
+        }
+    };
+    let hashed = common::hash_answer(&display, &common::new_salt(&path.display().to_string()));
+    Ok(Some(format!(
+        "{}{}{}",
+        &source[..start],
+        hashed,
+        &source[start + len..]
+    )))
+}
+
+/// Replaces the file at `path` with `content`, writing it under a temporary
+/// name first so that an interrupted write never leaves a truncated source.
+fn write_source(path: &Path, content: &str) -> Result<()> {
+    let part = PathBuf::from(format!("{}{}", path.display(), PART_SUFFIX));
+    File::create(&part)?.write_all(content.as_bytes())?;
+    fs::rename(&part, path)?;
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let program = &args[0];
+
+    let mut opts = Options::new();
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(m) => m,
+        Err(f) => {
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, f);
+            process::exit(ErrorKind::BadCli.exit_code());
+        }
+    };
+
+    if matches.opt_present("h") {
+        let short = format!("{} [FILE]...", opts.short_usage(&program));
+        println!("{}", opts.usage(&short));
+        return;
+    }
+
+    let files = if matches.free.is_empty() {
+        match problem_files(Path::new(PROBLEM_DIR)) {
+            Ok(files) => files,
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}: {}", program, PROBLEM_DIR, e);
+                process::exit(Error::from(e).kind().exit_code());
+            }
+        }
+    } else {
+        matches.free.iter().map(PathBuf::from).collect()
+    };
+
+    // Every file is converted before any is written, so that an error leaves
+    // the tree as it was.
+    let mut converted = vec![];
+    let mut code = 0;
+    for path in &files {
+        match convert(path) {
+            Ok(Some(source)) => converted.push((path, source)),
+            Ok(None) => {}
+            Err(e) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                if code == 0 {
+                    code = e.kind().exit_code();
+                }
+            }
+        }
+    }
+    if code != 0 {
+        let _ = writeln!(&mut io::stderr(), "{}: no answers hashed", program);
+        process::exit(code);
+    }
+
+    for &(path, ref source) in &converted {
+        if let Err(e) = write_source(path, source) {
+            let _ = writeln!(&mut io::stderr(), "{}: {}: {}", program, path.display(), e);
+            process::exit(e.kind().exit_code());
+        }
+    }
+    println!("hashed {} of {} answers", converted.len(), files.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{answer_offset, convert, write_source};
+    use std::{env, fs, process};
+
+    #[test]
+    fn offset() {
+        assert_eq!(Some(1), answer_offset("\"12345678\", solve);"));
+        let args = "title: \"Poker Hands\", difficulty: 10, tags: [\"dp\"], \
+                    \"376\", \"p054_poker.txt\", solve);";
+        assert_eq!(Some(args.find("376").unwrap()), answer_offset(args));
+        let args = "\n    title: \"Longest Collatz Sequence\",\n    \"87654321\",\n    solve\n);";
+        assert_eq!(Some(args.find("87654321").unwrap()), answer_offset(args));
+        assert_eq!(None, answer_offset("title: \"Untitled\", solve);"));
+    }
+
+    #[test]
+    fn convert_unregistered() {
+        let dir = env::temp_dir().join(format!("euler-hash-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("p999.rs");
+        let source = "problem!(\"12345678\", solve);\n";
+
+        write_source(&path, source).unwrap();
+        assert!(convert(&path).is_err());
+        assert_eq!(source, fs::read_to_string(&path).unwrap());
+        assert_eq!(1, fs::read_dir(&dir).unwrap().count());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
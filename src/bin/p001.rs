@@ -16,11 +16,16 @@ fn compute(bound: u32) -> u32 {
     (1..bound).filter(|&n| n % 3 == 0 || n % 5 == 0).sum()
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> u32 {
+    compute(1000)
 }
 
-problem!("233168", solve);
+problem!(
+    title: "Multiples of 3 or 5",
+    difficulty: 5,
+    "sha256:5681990bb3a1c201:a169f814e7c744abe7baa69a7f5d1ab9ac05b82219ef958b251705e685b9e8cc",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
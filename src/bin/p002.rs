@@ -24,10 +24,15 @@ fn compute(bound: u32) -> u32 {
         .sum()
 }
 
-fn solve() -> String {
-    compute(4000000).to_string()
+fn solve() -> u32 {
+    compute(4000000)
 }
-problem!("4613732", solve);
+problem!(
+    title: "Even Fibonacci Numbers",
+    difficulty: 5,
+    "sha256:9aea414132886b97:32a39e880f0c3969c2dea760be997f2864fdf9c61879f738b4808eeef31f54ce",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -20,10 +20,15 @@ fn compute(n: u64) -> u64 {
     n.factorize(&ps).map(|(base, _exp)| base).max().unwrap()
 }
 
-fn solve() -> String {
-    compute(600851475143).to_string()
+fn solve() -> u64 {
+    compute(600851475143)
 }
-problem!("6857", solve);
+problem!(
+    title: "Largest Prime Factor",
+    difficulty: 5,
+    "sha256:09d1b112209dc110:ed359d85864f6b11264173400ffe85a1334d49c6eeec739ce26ec90867df6730",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -38,11 +38,16 @@ fn compute(min: u32, max: u32) -> u32 {
     unreachable!()
 }
 
-fn solve() -> String {
-    compute(100, 999).to_string()
+fn solve() -> u32 {
+    compute(100, 999)
 }
 
-problem!("906609", solve);
+problem!(
+    title: "Largest Palindrome Product",
+    difficulty: 5,
+    "sha256:840ee4ed707cb7cf:97dfa0e6d24997bd3793fb11bf5b15799f1d2d32b8687208ebb90f5b15dcb9a6",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -24,11 +24,16 @@ fn compute(n: u32) -> u32 {
     fac.into_integer()
 }
 
-fn solve() -> String {
-    compute(20).to_string()
+fn solve() -> u32 {
+    compute(20)
 }
 
-problem!("232792560", solve);
+problem!(
+    title: "Smallest Multiple",
+    difficulty: 5,
+    "sha256:0e1e2ace0de34769:3500df8d30c709a64fefc40b7dce3ef28956c2b8a7c47302ef8c5990fcbb9b5f",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
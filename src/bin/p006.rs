@@ -27,11 +27,16 @@ fn compute(n: u32) -> u32 {
     square_of_sum(n) - sum_of_square(n)
 }
 
-fn solve() -> String {
-    compute(100).to_string()
+fn solve() -> u32 {
+    compute(100)
 }
 
-problem!("25164150", solve);
+problem!(
+    title: "Sum Square Difference",
+    difficulty: 5,
+    "sha256:4ca3de1fe8afcf29:e06b59fbf5269eda2ff003be551a2350af2c5f072d83ba7131d1afce3beb4dd5",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -19,11 +19,16 @@ fn compute(n: usize) -> u64 {
     PrimeSet::new().nth(n)
 }
 
-fn solve() -> String {
-    compute(10001 - 1).to_string()
+fn solve() -> u64 {
+    compute(10001 - 1)
 }
 
-problem!("104743", solve);
+problem!(
+    title: "10 001st Prime",
+    difficulty: 5,
+    "sha256:4a8c175144948fb2:aa856b02cef6e8cb97d4833c69e819b6776f5ff187843b4aa0ebf1fb79abf355",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
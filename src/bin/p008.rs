@@ -47,11 +47,16 @@ fn compute(prod_len: usize) -> u64 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(13).to_string()
+fn solve() -> u64 {
+    compute(13)
 }
 
-problem!("23514624000", solve);
+problem!(
+    title: "Largest Product in a Series",
+    difficulty: 5,
+    "sha256:ecd40186f37c741f:7f87578716d066cf3c649508ac21a470eda0538e991294307fdaaf1b836a6611",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
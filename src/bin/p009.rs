@@ -27,8 +27,13 @@ fn compute(sum: u32) -> u32 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> u32 {
+    compute(1000)
 }
 
-problem!("31875000", solve);
+problem!(
+    title: "Special Pythagorean Triplet",
+    difficulty: 5,
+    "sha256:ae1a389b71633915:a9ff462a0842a63e89c0feaacf54ceae0f6ad6bd7b8b2ed440c13939f0a5135d",
+    solve
+);
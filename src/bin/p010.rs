@@ -20,11 +20,16 @@ fn compute(limit: u64) -> u64 {
     ps.iter().take_while(|&p| p < limit).sum()
 }
 
-fn solve() -> String {
-    compute(2000000).to_string()
+fn solve() -> u64 {
+    compute(2000000)
 }
 
-problem!("142913828922", solve);
+problem!(
+    title: "Summation of Primes",
+    difficulty: 5,
+    "sha256:e822ab449774ecd7:d9078ecdfa9df3009c83ceca1907bd2797ce1123910f94448f5c4e9ebdbecf6d",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
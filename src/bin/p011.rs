@@ -86,8 +86,13 @@ fn compute(prod_len: usize) -> u32 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(4).to_string()
+fn solve() -> u32 {
+    compute(4)
 }
 
-problem!("70600674", solve);
+problem!(
+    title: "Largest Product in a Grid",
+    difficulty: 5,
+    "sha256:69d9ad6bf0fe8b50:ee3403e9b41e1f6929d6dc00b25c301b0f3041cbfc5c1acce3b17f318586426d",
+    solve
+);
@@ -26,11 +26,16 @@ fn compute(limit: u64) -> u64 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(500).to_string()
+fn solve() -> u64 {
+    compute(500)
 }
 
-problem!("76576500", solve);
+problem!(
+    title: "Highly Divisible Triangular Number",
+    difficulty: 5,
+    "sha256:666c08c10fd2b5b0:b69918807814a39267768e73ff2bf0871bb15d4c0f2d0d9cea3108e4e66b2f1e",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -130,4 +130,9 @@ fn solve() -> String {
     compute()
 }
 
-problem!("5537376230", solve);
+problem!(
+    title: "Large Sum",
+    difficulty: 5,
+    "sha256:b53fdce8b44c7c25:4e9bc20cc64a3771dfd83f88c4f49853778358a518bf33d01d16e4490d46b330",
+    solve
+);
@@ -13,6 +13,7 @@
 extern crate common;
 extern crate seq;
 
+use common::Params;
 use seq::Collatz;
 use std::collections::HashMap;
 
@@ -37,8 +38,28 @@ fn compute(limit: u64) -> u64 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+/// Follows every sequence to the end without memoization.
+fn compute_brute_force(limit: u64) -> u64 {
+    (2..limit)
+        .max_by_key(|&n| Collatz::new(n).take_while(|&x| x != 1).count())
+        .unwrap()
+}
+
+fn solve(params: &Params) -> u64 {
+    compute(params.get("limit"))
+}
+
+fn solve_brute_force(params: &Params) -> u64 {
+    compute_brute_force(params.get("limit"))
 }
 
-problem!("837799", solve);
+problem!(
+    title: "Longest Collatz Sequence",
+    difficulty: 5,
+    "sha256:249fc10c01d10d25:c5fd2c280e1e2ffd08875d6555be402c6b1576d6dd8c0fa6e89e5945006a8954",
+    variants["limit=10000"] {
+        memo: solve,
+        brute_force: solve_brute_force,
+    },
+    limit: u64 = 1000000; min 2
+);
@@ -19,11 +19,16 @@ fn compute(w: u64, h: u64) -> u64 {
     PrimeSet::new().combination(w + h, w)
 }
 
-fn solve() -> String {
-    compute(20, 20).to_string()
+fn solve() -> u64 {
+    compute(20, 20)
 }
 
-problem!("137846528820", solve);
+problem!(
+    title: "Lattice Paths",
+    difficulty: 5,
+    "sha256:7e9edb7c8e269b90:1cfcd819bcc6b4828dbf5dee53ec85562a7683fe8801deaea5027e37b3a5b33d",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
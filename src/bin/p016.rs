@@ -26,11 +26,16 @@ fn compute(base: u32, exp: u32) -> u32 {
         .sum()
 }
 
-fn solve() -> String {
-    compute(2, 1000).to_string()
+fn solve() -> u32 {
+    compute(2, 1000)
 }
 
-problem!("1366", solve);
+problem!(
+    title: "Power Digit Sum",
+    difficulty: 5,
+    "sha256:1b1ecc259b460c13:e808a49feb780e2b55bf72ded2d01d1b885d7bede4887037cc7abce8646dc3fd",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
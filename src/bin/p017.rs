@@ -102,11 +102,16 @@ fn compute(max: u32) -> u32 {
         .sum()
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> u32 {
+    compute(1000)
 }
 
-problem!("21124", solve);
+problem!(
+    title: "Number Letter Counts",
+    difficulty: 5,
+    "sha256:2b104125b9a328e8:d772d89237a7644546ebe7678bebfe054cab44f86654cfd3ed549d726d4f5f28",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -44,11 +44,17 @@ fn compute(input: &[&[u32]]) -> u32 {
     })[0]
 }
 
-fn solve() -> String {
-    compute(TRIANGLE).to_string()
+fn solve() -> u32 {
+    compute(TRIANGLE)
 }
 
-problem!("1074", solve);
+problem!(
+    title: "Maximum Path Sum I",
+    difficulty: 5,
+    tags: ["dp"],
+    "sha256:90609d4edb2e9db9:8ad0cac8eb2950e3ac3cdb3ebec5122824c6c1f3cccfec7a446f30647112b2c5",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -70,8 +70,13 @@ fn compute() -> u32 {
     result[0]
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u32 {
+    compute()
 }
 
-problem!("171", solve);
+problem!(
+    title: "Counting Sundays",
+    difficulty: 5,
+    "sha256:0cd46ef435e7f5d1:5a864032da275a9a12638a0f9185dc7b750d3ab569e1b91b7079955480d846f3",
+    solve
+);
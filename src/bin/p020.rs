@@ -29,11 +29,16 @@ fn compute(max: u32) -> u32 {
     .sum()
 }
 
-fn solve() -> String {
-    compute(100).to_string()
+fn solve() -> u32 {
+    compute(100)
 }
 
-problem!("648", solve);
+problem!(
+    title: "Factorial Digit Sum",
+    difficulty: 5,
+    "sha256:d8a41fee196a1638:d467e5fdd5d4063dbf9cf84ae382c03a296d0743eb1436612f24326a057ebb31",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -33,8 +33,13 @@ fn compute(limit: u64) -> u64 {
         .sum()
 }
 
-fn solve() -> String {
-    compute(10000).to_string()
+fn solve() -> u64 {
+    compute(10000)
 }
 
-problem!("31626", solve);
+problem!(
+    title: "Amicable Numbers",
+    difficulty: 5,
+    "sha256:455bb8c6af9ccfe1:4debf81d446d08b8f60b0422001daf0dc6c11acd5bb2b2794fa526dddb467dee",
+    solve
+);
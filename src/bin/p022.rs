@@ -34,7 +34,7 @@ fn compute(words: &[String]) -> u32 {
         .sum()
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mut words = vec![];
 
     for bytes in BufReader::new(file).split(b',') {
@@ -45,10 +45,16 @@ fn solve(file: File) -> io::Result<String> {
         words.push(String::from_utf8(bytes).unwrap());
     }
 
-    Ok(compute(&words).to_string())
+    Ok(compute(&words))
 }
 
-problem!("871198282", "p022_names.txt", solve);
+problem!(
+    title: "Names Scores",
+    difficulty: 5,
+    "sha256:95b28a7a06d371e4:a37a4475192aeb0589dc438dd7002292ceba9bed986d1bfb1eae0092facd0c4c",
+    "p022_names.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -13,6 +13,7 @@
 extern crate common;
 extern crate prime;
 
+use common::Params;
 use prime::{Factorize, PrimeSet};
 
 fn compute(max: u64) -> u64 {
@@ -43,8 +44,36 @@ fn compute(max: u64) -> u64 {
     sum_of_all_num - sum_of_sum_abundant
 }
 
-fn solve() -> String {
-    compute(28123).to_string()
+/// Sums the numbers that are not the sum of two abundant numbers, finding
+/// divisors by trial division.
+fn compute_brute_force(max: u64) -> u64 {
+    let is_abundant = |n: u64| (1..n).filter(|&d| n % d == 0).sum::<u64>() > n;
+    let abundant = (1..max + 1).filter(|&n| is_abundant(n)).collect::<Vec<_>>();
+    (1..max + 1)
+        .filter(|&n| {
+            !abundant
+                .iter()
+                .take_while(|&&a| a < n)
+                .any(|&a| abundant.binary_search(&(n - a)).is_ok())
+        })
+        .sum()
 }
 
-problem!("4179871", solve);
+fn solve(params: &Params) -> u64 {
+    compute(params.get("max"))
+}
+
+fn solve_brute_force(params: &Params) -> u64 {
+    compute_brute_force(params.get("max"))
+}
+
+problem!(
+    title: "Non-Abundant Sums",
+    difficulty: 5,
+    "sha256:b71afe8858ae4353:ce86bc86f37997645eebc43af731dda710fc04e744482a84dd51970d8749350b",
+    variants["max=1000"] {
+        sieve: solve,
+        brute_force: solve_brute_force,
+    },
+    max: u64 = 28123
+);
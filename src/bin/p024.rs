@@ -28,11 +28,16 @@ fn compute(mut idx: u64, mut set: Vec<u64>) -> u64 {
     Integer::from_digits(result.into_iter().rev(), 10)
 }
 
-fn solve() -> String {
-    compute(1000000 - 1, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).to_string()
+fn solve() -> u64 {
+    compute(1000000 - 1, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
 }
 
-problem!("2783915460", solve);
+problem!(
+    title: "Lexicographic Permutations",
+    difficulty: 5,
+    "sha256:69bbac04cf068107:e0569bdd71bc295dadafafcf30a8a45f9d6de0b382073904dbb3be457dafd03b",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -30,11 +30,16 @@ fn compute(limit_len: usize) -> usize {
         + 1
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> usize {
+    compute(1000)
 }
 
-problem!("4782", solve);
+problem!(
+    title: "1000-digit Fibonacci Number",
+    difficulty: 5,
+    "sha256:cdc18ae19e645728:b790fcff78f82f597146382b00f3bef5228c7f42f43ed4a421721f4dcf2d8b57",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
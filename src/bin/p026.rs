@@ -38,11 +38,16 @@ fn compute(limit: u32) -> u32 {
     (2..limit).max_by_key(|&n| get_cycle_len(n)).unwrap()
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> u32 {
+    compute(1000)
 }
 
-problem!("983", solve);
+problem!(
+    title: "Reciprocal Cycles",
+    difficulty: 5,
+    "sha256:dd6d596eb88f256d:491cf55cd41ca0ac89b7c70991c3c8b0b1a69b65dcf8aa456c14c153c8a5d90f",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
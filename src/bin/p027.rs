@@ -43,11 +43,16 @@ fn compute(limit: u64) -> i32 {
     a * b
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> i32 {
+    compute(1000)
 }
 
-problem!("-59231", solve);
+problem!(
+    title: "Quadratic Primes",
+    difficulty: 5,
+    "sha256:7880e9e3e655c21a:1fff8a27c9ce04c13a54eea1bf8e1ecc3157d299a86014a411bf7e2d91162bb3",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -42,11 +42,16 @@ fn compute(n: u32) -> u32 {
     (4 * n * n * n + 3 * n * n + 8 * n - 9) / 6
 }
 
-fn solve() -> String {
-    compute(1001).to_string()
+fn solve() -> u32 {
+    compute(1001)
 }
 
-problem!("669171001", solve);
+problem!(
+    title: "Number Spiral Diagonals",
+    difficulty: 5,
+    "sha256:ccfc0c30264d0c43:80d7e3b1a7144748b22e9c94ad8a05c3fbb8f7971206d81935ae15f23213416a",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
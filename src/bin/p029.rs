@@ -33,11 +33,16 @@ fn compute(a_max: u32, b_max: u32) -> u32 {
     set.len() as u32
 }
 
-fn solve() -> String {
-    compute(100, 100).to_string()
+fn solve() -> u32 {
+    compute(100, 100)
 }
 
-problem!("9183", solve);
+problem!(
+    title: "Distinct Powers",
+    difficulty: 5,
+    "sha256:9be59cb1d7edcfaf:3f0b95509174d703659159c1b665837d287338ec9ee75b7c3a99ff0bc68536f0",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
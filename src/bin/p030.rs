@@ -41,11 +41,16 @@ fn compute(len: usize, pow: u32) -> u32 {
     sum - 1
 }
 
-fn solve() -> String {
-    compute(6, 5).to_string()
+fn solve() -> u32 {
+    compute(6, 5)
 }
 
-problem!("443839", solve);
+problem!(
+    title: "Digit Fifth Powers",
+    difficulty: 5,
+    "sha256:d65ca136a330815c:fc3a4cd7f423991b272992b5545f109c0dfe16552545c727692ca871b16cc0e9",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
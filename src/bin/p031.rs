@@ -30,11 +30,16 @@ fn compute(sum: u32) -> u32 {
     count_ways(sum, coins)
 }
 
-fn solve() -> String {
-    compute(200).to_string()
+fn solve() -> u32 {
+    compute(200)
 }
 
-problem!("73682", solve);
+problem!(
+    title: "Coin Sums",
+    difficulty: 5,
+    "sha256:33dbd1b64c08dd21:79f7f49ab8015da9ec557468516fb70f1afd9c55f510f6abd555692be529e379",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -73,8 +73,13 @@ fn compute() -> u32 {
     products.into_iter().sum()
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u32 {
+    compute()
 }
 
-problem!("45228", solve);
+problem!(
+    title: "Pandigital Products",
+    difficulty: 5,
+    "sha256:d5926587cac005c3:672e6443e6d3572a63fff81bf0adb59be11469d623041cc18936a812009ec565",
+    solve
+);
@@ -55,8 +55,13 @@ fn compute() -> u32 {
     prod_denom / gcd
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u32 {
+    compute()
 }
 
-problem!("100", solve);
+problem!(
+    title: "Digit Cancelling Fractions",
+    difficulty: 5,
+    "sha256:fdafb674113f9084:3c19c4830e79d8b25258f826f4366c7e4090eb99225eef35f8e67a0aa25d8d2c",
+    solve
+);
@@ -34,8 +34,13 @@ fn compute() -> u32 {
     answer - 1 - 2
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u32 {
+    compute()
 }
 
-problem!("40730", solve);
+problem!(
+    title: "Digit Factorials",
+    difficulty: 5,
+    "sha256:cee6ac420d21f1b9:afde71ec6055fe91391b76b648176aa50a800a0bcd3f83705d6f4fe389fab7d8",
+    solve
+);
@@ -43,11 +43,16 @@ fn compute(limit: u64) -> usize {
         .count()
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve() -> usize {
+    compute(1000000)
 }
 
-problem!("55", solve);
+problem!(
+    title: "Circular Primes",
+    difficulty: 5,
+    "sha256:d9529031a6e500d1:6ca6d2e2ecfe3910980fea474cc8a53de3a2c8d64db7a419f2bceea89f104133",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
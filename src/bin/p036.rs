@@ -37,8 +37,13 @@ fn compute(limit: u32) -> u32 {
     sum
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve() -> u32 {
+    compute(1000000)
 }
 
-problem!("872187", solve);
+problem!(
+    title: "Double-base Palindromes",
+    difficulty: 5,
+    "sha256:2caca149e5372894:3cee2a2393c903a06aa4f0abf44595e644bf1193d89b5f62e789f0876d52630c",
+    solve
+);
@@ -65,11 +65,16 @@ fn compute() -> u64 {
         .sum()
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u64 {
+    compute()
 }
 
-problem!("748317", solve);
+problem!(
+    title: "Truncatable Primes",
+    difficulty: 5,
+    "sha256:811028b20e3cb059:fab637d1355c3fa68f9bd7b70080906fdf146748bb91692a3847c7e930bebd10",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
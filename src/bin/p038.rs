@@ -39,4 +39,9 @@ fn solve() -> String {
     compute()
 }
 
-problem!("932718654", solve);
+problem!(
+    title: "Pandigital Multiples",
+    difficulty: 5,
+    "sha256:f894dfc337238af7:3a361acf15905f5f6f7c3172c841304d2ff26df0b93f0044ebbaf1ebeac34023",
+    solve
+);
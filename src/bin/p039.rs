@@ -40,11 +40,16 @@ fn compute(limit: u32) -> u32 {
     *max_key
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> u32 {
+    compute(1000)
 }
 
-problem!("840", solve);
+problem!(
+    title: "Integer Right Triangles",
+    difficulty: 5,
+    "sha256:2edd5fc236cd9d96:4842f3ad9a42012b4c80f7a94b7155af69ca66c0745b899b93f2cca1e2118a45",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -69,12 +69,17 @@ fn compute(idxs: &[usize], radix: usize) -> usize {
     idxs.iter().map(|&i| nth_digit(i, radix)).product()
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let idxs = &[0, 9, 99, 999, 9999, 99999, 999999];
-    compute(idxs, 10).to_string()
+    compute(idxs, 10)
 }
 
-problem!("210", solve);
+problem!(
+    title: "Champernowne's Constant",
+    difficulty: 5,
+    "sha256:7dde8dfd5fbc9946:0660b64ff1d1f41dcd632e3a14d67bc6a03e58ceabbde5f03ab5c8b4d017fa09",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -35,8 +35,13 @@ fn compute() -> u64 {
     unreachable!()
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u64 {
+    compute()
 }
 
-problem!("7652413", solve);
+problem!(
+    title: "Pandigital Prime",
+    difficulty: 5,
+    "sha256:a44483e618ac0dc6:633fcef4e3b275e3d81ac3b32854c4cefbbc77ce6a875fb1aa64ca4b44cf4af4",
+    solve
+);
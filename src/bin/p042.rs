@@ -26,7 +26,7 @@ fn word_to_value(word: &str) -> u32 {
     value
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<usize> {
     let mut values = vec![];
 
     for bytes in BufReader::new(file).split(b',') {
@@ -40,14 +40,16 @@ fn solve(file: File) -> io::Result<String> {
     for t in TriangularNums::<u32>::new().take_while(|&t| t <= max_value) {
         is_tri[t as usize] = true;
     }
-    Ok(values
-        .iter()
-        .filter(|&&v| is_tri[v as usize])
-        .count()
-        .to_string())
+    Ok(values.iter().filter(|&&v| is_tri[v as usize]).count())
 }
 
-problem!("162", "p042_words.txt", solve);
+problem!(
+    title: "Coded Triangle Numbers",
+    difficulty: 5,
+    "sha256:a15c476057061317:e3c0ed405fd8adeba0dcb54766feeb657d3d76a93b01462a0fc78271143cdfc9",
+    "p042_words.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
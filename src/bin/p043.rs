@@ -103,16 +103,21 @@ fn update_pandigimal_list(list: Vec<Pandigimal>, base: u64, len: usize) -> Vec<P
     result
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let mut result = create_pandigimal_list(17, 3);
     for &base in &[13, 11, 7, 5, 3, 2, 1] {
         result = update_pandigimal_list(result, base, 3);
     }
 
-    result.iter().map(|pd| pd.to_u64()).sum::<u64>().to_string()
+    result.iter().map(|pd| pd.to_u64()).sum::<u64>()
 }
 
-problem!("16695334890", solve);
+problem!(
+    title: "Sub-string Divisibility",
+    difficulty: 5,
+    "sha256:b1413b8bfcdfcdc1:dd79a3e010a0768de93663a6cad6aa6996b3668da8b5d437229623caa0f44e03",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -31,7 +31,7 @@ fn is_pentagonal(n: u32, table: &[u32]) -> bool {
 // 2*P[j] = P[m] - P[n] > 0
 //
 // find minimum n, where n < m
-fn solve() -> String {
+fn solve() -> u32 {
     let limit = 10000;
     let pentagonals = (0..limit).map(nth_pentagonal).collect::<Vec<_>>();
 
@@ -44,7 +44,7 @@ fn solve() -> String {
             }
             if is_pentagonal(pm - pn, &pentagonals) {
                 if is_pentagonal(pm + pn, &pentagonals) {
-                    return (pm - pn).to_string();
+                    return pm - pn;
                 }
             }
         }
@@ -52,4 +52,9 @@ fn solve() -> String {
     unreachable!()
 }
 
-problem!("5482660", solve);
+problem!(
+    title: "Pentagon Numbers",
+    difficulty: 5,
+    "sha256:eafd75f82a772350:7711ca92ec14e363d39d5b5711f1286be45e51dc0abdd6f0343e249f5534a90c",
+    solve
+);
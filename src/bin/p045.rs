@@ -70,11 +70,16 @@ fn compute(start: u32) -> u32 {
     triangle(t_i)
 }
 
-fn solve() -> String {
-    compute(40755 + 1).to_string()
+fn solve() -> u32 {
+    compute(40755 + 1)
 }
 
-problem!("1533776805", solve);
+problem!(
+    title: "Triangular, Pentagonal, and Hexagonal",
+    difficulty: 5,
+    "sha256:276d1158c8032c06:d93b110aec71843651fd9a94d4612e718706a354258dfd9d64da3855290a04b5",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
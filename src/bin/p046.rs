@@ -30,7 +30,7 @@ fn is_goldbach(ps: &PrimeSet, n: u64) -> bool {
     false
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let ps = PrimeSet::new();
     (3..)
         .step_by(2)
@@ -38,7 +38,11 @@ fn solve() -> String {
         .skip_while(|&n| is_goldbach(&ps, n))
         .next()
         .unwrap()
-        .to_string()
 }
 
-problem!("5777", solve);
+problem!(
+    title: "Goldbach's Other Conjecture",
+    difficulty: 5,
+    "sha256:55929d777a2d8893:98c2ff50f19cc9119bf797e2ab413526738d213cb954b41083928658fb4e6354",
+    solve
+);
@@ -34,11 +34,16 @@ fn compute(len: usize, num_factor: usize) -> usize {
     unreachable!()
 }
 
-fn solve() -> String {
-    compute(4, 4).to_string()
+fn solve() -> usize {
+    compute(4, 4)
 }
 
-problem!("134043", solve);
+problem!(
+    title: "Distinct Primes Factors",
+    difficulty: 5,
+    "sha256:8acc66427e95c692:2447edce73ffc60f42b6380abd01a528076bd80fb8c11f4784c3b0d03a335e98",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -31,11 +31,16 @@ fn compute(max: u64, modulo: u64) -> u64 {
     sum
 }
 
-fn solve() -> String {
-    compute(1000, 100_0000_0000).to_string()
+fn solve() -> u64 {
+    compute(1000, 100_0000_0000)
 }
 
-problem!("9110846700", solve);
+problem!(
+    title: "Self Powers",
+    difficulty: 5,
+    "sha256:b7dc109a52b336f3:2bc8308e482390b7e5eddfd0052ffd9c200ae40842ba2f1359fc8c2b4066d4da",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
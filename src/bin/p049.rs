@@ -36,4 +36,9 @@ fn solve() -> String {
     format!("{}{}{}", p1, p2, p3)
 }
 
-problem!("296962999629", solve);
+problem!(
+    title: "Prime Permutations",
+    difficulty: 5,
+    "sha256:6bbb658a6513a85a:38106b8c579daa65b88cdee9f7c31e8c722fde10153f3deffb3b60aca86df0c6",
+    solve
+);
@@ -69,11 +69,16 @@ fn compute(limit: u64) -> (u64, usize) {
     (num, len)
 }
 
-fn solve() -> String {
-    compute(1000000).0.to_string()
+fn solve() -> u64 {
+    compute(1000000).0
 }
 
-problem!("997651", solve);
+problem!(
+    title: "Consecutive Prime Sum",
+    difficulty: 5,
+    "sha256:1cf7c63e2580dc1e:bbb8126e71656192be5afdb216f4d2229628c75e6e4559c7e15e10eb69d9e4ef",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -53,11 +53,16 @@ fn compute(num_value: usize) -> u64 {
     unreachable!()
 }
 
-fn solve() -> String {
-    compute(8).to_string()
+fn solve() -> u64 {
+    compute(8)
 }
 
-problem!("121313", solve);
+problem!(
+    title: "Prime Digit Replacements",
+    difficulty: 15,
+    "sha256:2e655c4bd5aafda3:672e16552258b43cbe9ab34a8714db8cceeae362f03cb48da54404dfe8ecfa20",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
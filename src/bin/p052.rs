@@ -63,8 +63,13 @@ fn compute() -> u32 {
     }
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u32 {
+    compute()
 }
 
-problem!("142857", solve);
+problem!(
+    title: "Permuted Multiples",
+    difficulty: 5,
+    "sha256:ddca660763d6d5ac:901d406f620954496d294651da47c4daefd0b1fe02e2b5155bf621cf968ec5c3",
+    solve
+);
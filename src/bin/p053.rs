@@ -48,8 +48,13 @@ fn compute() -> u32 {
     cnt
 }
 
-fn solve() -> String {
-    compute().to_string()
+fn solve() -> u32 {
+    compute()
 }
 
-problem!("4075", solve);
+problem!(
+    title: "Combinatoric Selections",
+    difficulty: 5,
+    "sha256:d411ad9c64d6781c:d909cd3d5cfce704e212d4e2a431f0e0e60122eb2cc2c9be503487786f8121d3",
+    solve
+);
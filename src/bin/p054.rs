@@ -306,7 +306,7 @@ impl Hand {
     }
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mut p1_win = 0;
     let mut _p2_win = 0;
     let mut _draw = 0;
@@ -326,10 +326,16 @@ fn solve(file: File) -> io::Result<String> {
         }
     }
 
-    Ok(p1_win.to_string())
+    Ok(p1_win)
 }
 
-problem!("376", "p054_poker.txt", solve);
+problem!(
+    title: "Poker Hands",
+    difficulty: 10,
+    "sha256:c7c67a6bfd78e532:83aa9077f856a3c0ebe4e9d53a9bb531ea6a562be557fc70cfff4dbc6f38b23c",
+    "p054_poker.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
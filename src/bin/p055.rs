@@ -41,11 +41,16 @@ fn compute(max: u32, limit: usize) -> usize {
     (1..(max + 1)).filter(|&n| is_lychrel(n, limit)).count()
 }
 
-fn solve() -> String {
-    compute(10000, 50).to_string()
+fn solve() -> usize {
+    compute(10000, 50)
 }
 
-problem!("249", solve);
+problem!(
+    title: "Lychrel Numbers",
+    difficulty: 5,
+    "sha256:32ee6b806fd2ef3d:a8e99bd062cd41d226ff0251b8ec7eecbfe5e40848be7715e0a70250a490e8d0",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
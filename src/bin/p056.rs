@@ -33,8 +33,13 @@ fn compute(a: u32, b: u32) -> u32 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(100, 100).to_string()
+fn solve() -> u32 {
+    compute(100, 100)
 }
 
-problem!("972", solve);
+problem!(
+    title: "Powerful Digit Sum",
+    difficulty: 5,
+    "sha256:7a6a0f91f9e04a43:b9469091ede7b62ae54a4c479b760a283a4fe35706cb1bdaff6d991026447cfd",
+    solve
+);
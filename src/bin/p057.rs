@@ -60,15 +60,19 @@ impl Iterator for Frac {
     }
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     Frac::new()
         .take(1000)
         .filter(|&(ref n, ref d)| n.to_string().len() > d.to_string().len())
         .count()
-        .to_string()
 }
 
-problem!("153", solve);
+problem!(
+    title: "Square Root Convergents",
+    difficulty: 5,
+    "sha256:477afea552d206eb:de74b818092726a352c9797da85ddcbd4046bdef9492c28b269603ed81477cd2",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -43,8 +43,13 @@ fn compute(numer: u32, denom: u32) -> u64 {
     }
 }
 
-fn solve() -> String {
-    compute(1, 10).to_string()
+fn solve() -> u64 {
+    compute(1, 10)
 }
 
-problem!("26241", solve);
+problem!(
+    title: "Spiral Primes",
+    difficulty: 5,
+    "sha256:961f65e148f8cfc3:23aa4a6706aac493148bd897b4e25fe5b9cc2c12ce4a0c8d7b3dde645dcf0664",
+    solve
+);
@@ -97,7 +97,7 @@ fn read_file(file: File) -> io::Result<Vec<u8>> {
     Ok(code_list)
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     const KEY_LEN: usize = 3;
     let code_list = read_file(file)?;
 
@@ -122,7 +122,13 @@ fn solve(file: File) -> io::Result<String> {
         .map(|(&n, &key)| (n ^ key) as u32)
         .sum::<u32>();
 
-    Ok(sum.to_string())
+    Ok(sum)
 }
 
-problem!("107359", "p059_cipher.txt", solve);
+problem!(
+    title: "XOR Decryption",
+    difficulty: 5,
+    "sha256:d091b667cbcda882:b1572945c74fc8784595e73390dc132cd20561abbcc9d17427b5644e2a39be7a",
+    "p059_cipher.txt",
+    solve
+);
@@ -42,6 +42,7 @@ impl Iterator for ConcatPrimeNums {
     type Item = (u64, Vec<u64>);
 
     fn next(&mut self) -> Option<(u64, Vec<u64>)> {
+        let _phase = common::phase("pairs");
         let n = self.iter.next().unwrap();
         let pairs = self
             .ps
@@ -104,6 +105,7 @@ fn compute(len: usize) -> Vec<u64> {
 
     for (n, pairs) in ConcatPrimeNums::new(&prime) {
         if pairs.len() >= len {
+            let _phase = common::phase("chains");
             for set in find_chain(&pairs, &[n], &map) {
                 if set.len() >= len {
                     return set;
@@ -116,11 +118,16 @@ fn compute(len: usize) -> Vec<u64> {
     unreachable!();
 }
 
-fn solve() -> String {
-    compute(5).into_iter().sum::<u64>().to_string()
+fn solve() -> u64 {
+    compute(5).into_iter().sum::<u64>()
 }
 
-problem!("26033", solve);
+problem!(
+    title: "Prime Pair Sets",
+    difficulty: 20,
+    "sha256:cf3552779e12544b:a3e51a317042d946cca9a1ec3ca37411e1afc6155a0f2f12583db426a0430202",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
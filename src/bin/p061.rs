@@ -104,7 +104,7 @@ fn cycle_to_nums(map: &[u32]) -> Vec<u32> {
     result
 }
 
-fn solve() -> String {
+fn solve() -> u32 {
     let map: &[fn(u32) -> u32] = &[
         triangle, square, pentagonal, hexagonal, heptagonal, octagonal,
     ];
@@ -112,10 +112,14 @@ fn solve() -> String {
         .iter()
         .map(|vs| cycle_to_nums(&vs).into_iter().sum::<u32>())
         .sum::<u32>()
-        .to_string()
 }
 
-problem!("28684", solve);
+problem!(
+    title: "Cyclical Figurate Numbers",
+    difficulty: 20,
+    "sha256:69f211e7524437ff:223e2f2704f52bfdf8873260b8e893eb24793cc575f44be8b5c4cbc9bacab124",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -57,11 +57,16 @@ fn perm_exact(cnt: usize) -> HashSet<u64> {
     unreachable!()
 }
 
-fn solve() -> String {
-    perm_exact(5).iter().min().unwrap().to_string()
+fn solve() -> u64 {
+    *perm_exact(5).iter().min().unwrap()
 }
 
-problem!("127035954683", solve);
+problem!(
+    title: "Cubic Permutations",
+    difficulty: 15,
+    "sha256:5b8d43494306ce6d:b8e3fec3b2ce46365c2bef7c8a6ca288328e5295797790ade29ce845e0a7cc9d",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
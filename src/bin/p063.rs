@@ -17,7 +17,7 @@ extern crate num_traits;
 use num_bigint::BigUint;
 use num_traits::FromPrimitive;
 
-fn solve() -> String {
+fn solve() -> u32 {
     let mut cnt = 1; // a == 1
     for a in (2usize..10).filter_map::<BigUint, _>(|a| FromPrimitive::from_usize(a)) {
         let mut n = 0;
@@ -34,7 +34,12 @@ fn solve() -> String {
         }
     }
 
-    cnt.to_string()
+    cnt
 }
 
-problem!("49", solve);
+problem!(
+    title: "Powerful Digit Counts",
+    difficulty: 5,
+    "sha256:9d7dc9a0d109886b:5a203e9ac04c11e093c9fca34ed41f0e4ace2c4e8efcf30465586f37d259db74",
+    solve
+);
@@ -16,13 +16,17 @@ extern crate num_integer;
 
 use num_integer::Integer;
 
-fn solve() -> String {
+fn solve() -> usize {
     (1u32..10001)
         .map(cont_frac::sqrt)
         .map(|(_a0, an)| an.len())
         .filter(|an| an.is_odd())
         .count()
-        .to_string()
 }
 
-problem!("1322", solve);
+problem!(
+    title: "Odd Period Square Roots",
+    difficulty: 20,
+    "sha256:3581dfdb4f5c11f7:445325e7a44a29ff72a6d0e00f310f4c18ee5f95b5bf5e335855067d14f0f112",
+    solve
+);
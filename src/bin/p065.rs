@@ -24,7 +24,7 @@ fn napier_seq(i: u32) -> u32 {
     }
 }
 
-fn solve() -> String {
+fn solve() -> u32 {
     let len = 100;
     let napier = (0u32..len).map(napier_seq);
     let (n, _d) = cont_frac::fold::<BigUint, _>(napier);
@@ -32,7 +32,11 @@ fn solve() -> String {
         .chars()
         .filter_map(|c| c.to_digit(10))
         .sum::<u32>()
-        .to_string()
 }
 
-problem!("272", solve);
+problem!(
+    title: "Convergents of e",
+    difficulty: 15,
+    "sha256:15b760f470282211:c3db1a3d03c70d516cd9c0492cc1e7626c42c068a84b3921f812b969ce508f70",
+    solve
+);
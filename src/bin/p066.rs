@@ -18,7 +18,7 @@ extern crate num_bigint;
 use iter::Difference;
 use num_bigint::BigUint;
 
-fn solve() -> String {
+fn solve() -> u32 {
     let ns = 1..;
     let sq = (1..).map(|x| x * x);
 
@@ -26,7 +26,11 @@ fn solve() -> String {
         .take_while(|&d| d <= 1000)
         .max_by_key(|&d| cont_frac::solve_pel::<BigUint>(d).0)
         .unwrap()
-        .to_string()
 }
 
-problem!("661", solve);
+problem!(
+    title: "Diophantine Equation",
+    difficulty: 25,
+    "sha256:2f9033341084f345:64b4ae6e80d2048647fecc0f08d2a871b6835dd0748539138f730d63e15655bc",
+    solve
+);
@@ -17,7 +17,7 @@ use std::fs::File;
 use std::io::prelude::*;
 use std::io::{self, BufReader};
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mut triangle = BufReader::new(file)
         .lines()
         .filter_map(|line| line.ok())
@@ -35,7 +35,14 @@ fn solve(file: File) -> io::Result<String> {
             .collect()
     })[0];
 
-    Ok(ans.to_string())
+    Ok(ans)
 }
 
-problem!("7273", "p067_triangle.txt", solve);
+problem!(
+    title: "Maximum Path Sum II",
+    difficulty: 5,
+    tags: ["dp"],
+    "sha256:b9fd5f311ce89839:0215a4c56476d6b78a1e97a3b634de81b05b10d27898df4d1bba3231f4f96a5d",
+    "p067_triangle.txt",
+    solve
+);
@@ -32,4 +32,9 @@ fn solve() -> String {
     "6531031914842725".to_string()
 }
 
-problem!("6531031914842725", solve);
+problem!(
+    title: "Magic 5-gon Ring",
+    difficulty: 25,
+    "sha256:aee8de4404e6b2c4:85c5cad58157a74e047f1b02e3fae012c518267a954ec37b7dd00a47451b1e37",
+    solve
+);
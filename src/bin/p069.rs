@@ -27,11 +27,16 @@ fn compute(limit: u64) -> u64 {
     n
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve() -> u64 {
+    compute(1000000)
 }
 
-problem!("510510", solve);
+problem!(
+    title: "Totient Maximum",
+    difficulty: 10,
+    "sha256:bf327efb751a90eb:aaaecf2eb04be5a98cf478af673a31f3eb1cd1fae7f4f215da749e16b50932b3",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
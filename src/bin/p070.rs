@@ -61,8 +61,13 @@ fn compute(limit: u64) -> u64 {
     min_n
 }
 
-fn solve() -> String {
-    compute(10000000).to_string()
+fn solve() -> u64 {
+    compute(10000000)
 }
 
-problem!("8319823", solve);
+problem!(
+    title: "Totient Permutation",
+    difficulty: 20,
+    "sha256:9a03495840cfa822:e0cefbf0d2c1a8bd1ccdfc4fcfea02f0d5638dbd8cfe57a732d5e0291c880916",
+    solve
+);
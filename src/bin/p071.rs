@@ -29,11 +29,16 @@ fn compute(limit: u64) -> u64 {
     max_n
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve() -> u64 {
+    compute(1000000)
 }
 
-problem!("428570", solve);
+problem!(
+    title: "Ordered Fractions",
+    difficulty: 10,
+    "sha256:e236919327cad2de:4837ec616b817e0733c52a811deacbf6a72601fb26fbaa32f379e19e4324ecd1",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
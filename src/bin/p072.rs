@@ -11,8 +11,11 @@
 
 #[macro_use(problem)]
 extern crate common;
+extern crate num_integer;
 extern crate prime;
 
+use common::Params;
+use num_integer::Integer;
 use prime::PrimeSet;
 
 fn compute(limit: u64) -> u64 {
@@ -33,16 +36,37 @@ fn compute(limit: u64) -> u64 {
     v.into_iter().sum()
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+/// Counts the fractions with coprime numerator and denominator one by one.
+fn compute_brute_force(limit: u64) -> u64 {
+    (2..limit + 1)
+        .map(|d| (1..d).filter(|&n| n.gcd(&d) == 1).count() as u64)
+        .sum()
 }
 
-problem!("303963552391", solve);
+fn solve(params: &Params) -> u64 {
+    compute(params.get("limit"))
+}
+
+fn solve_brute_force(params: &Params) -> u64 {
+    compute_brute_force(params.get("limit"))
+}
+
+problem!(
+    title: "Counting Fractions",
+    difficulty: 20,
+    "sha256:0123010d8ca676e1:d35dd611eedd9537a4d4bd65a0b865951a513cee1fa4113f723ebfe5153f5878",
+    variants["limit=1000"] {
+        sieve: solve,
+        brute_force: solve_brute_force,
+    },
+    limit: u64 = 1000000
+);
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn eight() {
         assert_eq!(21, super::compute(8));
+        assert_eq!(21, super::compute_brute_force(8));
     }
 }
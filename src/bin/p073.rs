@@ -20,11 +20,16 @@ fn count_between(a: (u32, u32), b: (u32, u32), max_denom: u32) -> u32 {
     count_between(a, mid, max_denom) + count_between(mid, b, max_denom) + 1
 }
 
-fn solve() -> String {
-    count_between((1, 3), (1, 2), 12000).to_string()
+fn solve() -> u32 {
+    count_between((1, 3), (1, 2), 12000)
 }
 
-problem!("7295372", solve);
+problem!(
+    title: "Counting Fractions in a Range",
+    difficulty: 15,
+    "sha256:5c025e512385c128:16992ee9fc15b053223b37cf3d57815f66796d3fb3ebfa7a986b5993e172a52f",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -76,7 +76,7 @@ fn get_chain_len(n: u32, map: &mut [Length], fs: &[u32; 10]) -> usize {
     chain_len + loop_len
 }
 
-fn solve() -> String {
+fn solve() -> u32 {
     let limit = 1000000;
     let factorial = {
         let mut val = [1; 10];
@@ -95,10 +95,15 @@ fn solve() -> String {
         }
     }
 
-    cnt.to_string()
+    cnt
 }
 
-problem!("402", solve);
+problem!(
+    title: "Digit Factorial Chains",
+    difficulty: 15,
+    "sha256:6a55efd6b01fc77d:ddb20712dffde40f4d7a73aea700cf514706f5e05414c701bf9f9b05783e49c1",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -17,7 +17,7 @@ extern crate seq;
 use integer::Integer;
 use seq::PrimitivePythagoreans;
 
-fn solve() -> String {
+fn solve() -> usize {
     let limit = 1500000u64;
     let mut v = vec![0; (limit + 1) as usize];
 
@@ -30,7 +30,12 @@ fn solve() -> String {
         }
     }
 
-    v.iter().filter(|&x| x == &1).count().to_string()
+    v.iter().filter(|&x| x == &1).count()
 }
 
-problem!("161667", solve);
+problem!(
+    title: "Singular Integer Right Triangles",
+    difficulty: 25,
+    "sha256:1dc3d0b9fc904677:6816240316e4face1301f14082f46711748d59832b2c4fe768616858c6d4ba42",
+    solve
+);
@@ -32,11 +32,17 @@ fn count_way(sum: u32) -> u32 {
     }
 }
 
-fn solve() -> String {
-    count_way(100).to_string()
+fn solve() -> u32 {
+    count_way(100)
 }
 
-problem!("190569291", solve);
+problem!(
+    title: "Counting Summations",
+    difficulty: 10,
+    tags: ["dp"],
+    "sha256:44f6bca3941c220d:66fd8224017c18239ca99244b76cfd8f6817bda1fcecbf61f24c38506da3d49e",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -54,17 +54,22 @@ fn count_way(ps: &PrimeSet, sum: u64, map: &mut HashMap<(u64, usize), u32>) -> u
     }
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let ps = PrimeSet::new();
     let mut map = HashMap::new();
     (1..)
         .skip_while(|&n| count_way(&ps, n, &mut map) <= 5000)
         .next()
         .unwrap()
-        .to_string()
 }
 
-problem!("71", solve);
+problem!(
+    title: "Prime Summations",
+    difficulty: 25,
+    tags: ["dp"],
+    "sha256:3ddb8f720f1bfdd3:0d92d8ddfbec4adf61e55f7360d3ddc1a3564aa2939a121faa3f5c2f417de24a",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
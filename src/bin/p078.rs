@@ -12,18 +12,22 @@
 #[macro_use(problem)]
 extern crate common;
 
+use common::Params;
+
 const MILLION: i32 = 1000000;
 
 fn penta(n: i32) -> i32 {
     n * (3 * n - 1) / 2
 }
 
-fn solve() -> String {
-    let mut v = [0; 65536];
-    v[0] = 1;
+fn solve(params: &Params) -> i32 {
+    // The terms are reduced below the divisor, so their sums fit in `i64`
+    // whatever `i32` divisor is given.
+    let divisor = i64::from(params.get::<i32>("divisor"));
+    let mut v = vec![1i64];
 
     for n in 1.. {
-        let mut way = 0;
+        let mut way = 0i64;
 
         for i in 0.. {
             let k = i % 4;
@@ -43,16 +47,23 @@ fn solve() -> String {
                 1 => way + v[idx],
                 2 => way - v[idx],
                 _ => way - v[idx],
-            } % MILLION
+            } % divisor
         }
-        v[n as usize] = way;
+        v.push(way);
 
         if way == 0 {
-            return n.to_string();
+            return n;
         }
     }
 
     unreachable!()
 }
 
-problem!("55374", solve);
+problem!(
+    title: "Coin Partitions",
+    difficulty: 30,
+    tags: ["dp"],
+    "sha256:5a3623a0fe5a8a52:49d2105129b550d21347f1e74753f40b48285dd2809e0aa5881146890f3f324c",
+    solve,
+    divisor: i32 = MILLION; min 1
+);
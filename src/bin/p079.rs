@@ -34,4 +34,10 @@ fn solve(file: File) -> io::Result<String> {
     Ok(s)
 }
 
-problem!("73162890", "p079_keylog.txt", solve);
+problem!(
+    title: "Passcode Derivation",
+    difficulty: 5,
+    "sha256:8b535ff5a41dc126:6e8fe36b469b8a193e88618ff9fd3f2df73377eaa178339e7b631822c9dcba25",
+    "p079_keylog.txt",
+    solve
+);
@@ -51,17 +51,21 @@ fn sqrt_digit_sum(n: u32, precision: usize) -> u32 {
         .sum()
 }
 
-fn solve() -> String {
+fn solve() -> u32 {
     let ns = 2u32..101;
     let sq = (2u32..101).map(|x| x * x);
 
     Difference::new(ns, sq)
         .map(|n| sqrt_digit_sum(n, 100))
         .sum::<u32>()
-        .to_string()
 }
 
-problem!("40886", solve);
+problem!(
+    title: "Square Root Digital Expansion",
+    difficulty: 20,
+    "sha256:aadb1e30d7fe8031:e28a6a63883069b6bbb8b39aab2caf0b19f320aee01c46b240faadcfee8caa02",
+    solve
+);
 
 #[cfg(test)]
 mod test {
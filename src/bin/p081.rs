@@ -47,12 +47,19 @@ fn minimal_path_sum(mut mat: Vec<Vec<u32>>) -> u32 {
     mat[h - 1][w - 1]
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mat = read_matrix(file)?;
-    Ok(minimal_path_sum(mat).to_string())
+    Ok(minimal_path_sum(mat))
 }
 
-problem!("427337", "p081_matrix.txt", solve);
+problem!(
+    title: "Path Sum: Two Ways",
+    difficulty: 10,
+    tags: ["dp"],
+    "sha256:b7c39150b22c41aa:c58ce04d4bd13363f78df428d4d90276c0159491c4847d15e60228c8c90f5aee",
+    "p081_matrix.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -63,12 +63,19 @@ fn minimal_path_sum(mat: Vec<Vec<u32>>) -> u32 {
     (0..h).map(|y| sum[y][w - 1]).min().unwrap()
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mat = read_matrix(file)?;
-    Ok(minimal_path_sum(mat).to_string())
+    Ok(minimal_path_sum(mat))
 }
 
-problem!("260324", "p082_matrix.txt", solve);
+problem!(
+    title: "Path Sum: Three Ways",
+    difficulty: 20,
+    tags: ["dp"],
+    "sha256:29532a1e490ba183:ddd254e7933ed87ed48f38c36e74f78f3fedf2300160c6fda0519500948a5b0c",
+    "p082_matrix.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
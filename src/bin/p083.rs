@@ -122,12 +122,18 @@ fn minimal_path_sum(mat: Vec<Vec<u32>>) -> u32 {
     dist[h - 1][w - 1]
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mat = read_matrix(file)?;
-    Ok(minimal_path_sum(mat).to_string())
+    Ok(minimal_path_sum(mat))
 }
 
-problem!("425185", "p083_matrix.txt", solve);
+problem!(
+    title: "Path Sum: Four Ways",
+    difficulty: 25,
+    "sha256:a2355b0049fcc0ea:fe10ab2116a7651e00339d57c4027f103546fa42a8d5a5c716ad5d52a9eccc11",
+    "p083_matrix.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -231,7 +231,12 @@ fn solve() -> String {
     )
 }
 
-problem!("101524", solve);
+problem!(
+    title: "Monopoly Odds",
+    difficulty: 35,
+    "sha256:bd718fed0a150afe:c4271ada0d4cd27829011211c7d9b63a9a761ca30a09138d0854d947b4ae9055",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
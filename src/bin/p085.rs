@@ -35,7 +35,7 @@ fn check_distance(min_dist: &mut u32, min_pos: &mut (u32, u32), pos: (u32, u32),
     }
 }
 
-fn solve() -> String {
+fn solve() -> u32 {
     let target = 2000000;
 
     let mut x = 1;
@@ -60,7 +60,12 @@ fn solve() -> String {
     }
 
     let (x, y) = nearest;
-    (x * y).to_string()
+    x * y
 }
 
-problem!("2772", solve);
+problem!(
+    title: "Counting Rectangles",
+    difficulty: 15,
+    "sha256:4c8cd815e43a9eae:60345e2f42cbb0922477e4a9c733159aafa816ef39905d61e16c52592745a7fc",
+    solve
+);
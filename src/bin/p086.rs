@@ -64,11 +64,16 @@ fn get_min_m(limit: u32) -> u32 {
     m
 }
 
-fn solve() -> String {
-    get_min_m(1000000).to_string()
+fn solve() -> u32 {
+    get_min_m(1000000)
 }
 
-problem!("1818", solve);
+problem!(
+    title: "Cuboid Route",
+    difficulty: 35,
+    "sha256:4159f7e43edca73f:e039cecde270bb826a86577410950c5d682e3e0d8e64ea268b9f6b73f6bfdf68",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -51,11 +51,16 @@ fn compute(limit: u64) -> u32 {
     cnt
 }
 
-fn solve() -> String {
-    compute(50000000).to_string()
+fn solve() -> u32 {
+    compute(50000000)
 }
 
-problem!("1097343", solve);
+problem!(
+    title: "Prime Power Triples",
+    difficulty: 20,
+    "sha256:a1b0cec57d10fee6:66cefa33935cf92d6eddfc8c2d9073428a3b05bddb8537f7bdb020179779c072",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -81,11 +81,16 @@ fn compute(limit: usize) -> u32 {
     nums.into_iter().sum()
 }
 
-fn solve() -> String {
-    compute(12000).to_string()
+fn solve() -> u32 {
+    compute(12000)
 }
 
-problem!("7587457", solve);
+problem!(
+    title: "Product-sum Numbers",
+    difficulty: 40,
+    "sha256:4fb3a4b1d0be13bf:83cd9d0799283d7705f4ff1e69d262e2375f14cb0d5e300be372028ba22589b3",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -113,17 +113,23 @@ fn to_roman(mut n: u32) -> String {
     s
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<usize> {
     let mut sum = 0;
     for line in BufReader::new(file).lines() {
         let line = line?;
         let line = line.trim();
         sum += line.len() - to_roman(from_roman(line).unwrap()).len();
     }
-    Ok(sum.to_string())
+    Ok(sum)
 }
 
-problem!("743", "p089_roman.txt", solve);
+problem!(
+    title: "Roman Numerals",
+    difficulty: 20,
+    "sha256:ab972f84cedca75d:7e44f26f8cffb73b639566b659e62df1e307d0828258e3e9f6b19060e4f50293",
+    "p089_roman.txt",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
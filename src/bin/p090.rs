@@ -15,7 +15,7 @@ extern crate iter;
 
 use iter::BitCombination;
 
-fn solve() -> String {
+fn solve() -> u32 {
     let all_combs = BitCombination::new(6, 10)
         .map(|mut set| {
             match (set.contains(6), set.contains(9)) {
@@ -47,7 +47,12 @@ fn solve() -> String {
             }
         }
     }
-    cnt.to_string()
+    cnt
 }
 
-problem!("1217", solve);
+problem!(
+    title: "Cube Digit Pairs",
+    difficulty: 40,
+    "sha256:75255b7127e63dee:225242be2603fd0a5427dd319a9acfc2f284d4b05d0a06768e3cb53132494109",
+    solve
+);
@@ -38,11 +38,16 @@ fn compute(x_max: u32, y_max: u32) -> u32 {
     count_right_at_o(x_max, y_max) + count_right_at_p(x_max, y_max) * 2
 }
 
-fn solve() -> String {
-    compute(50, 50).to_string()
+fn solve() -> u32 {
+    compute(50, 50)
 }
 
-problem!("14234", solve);
+problem!(
+    title: "Right Triangles with Integer Coordinates",
+    difficulty: 25,
+    "sha256:d78a55c973931def:043d9e6343aae16324cd87bd2659aacd76d04cbd425f6aaa49bb0ce41962dbf9",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
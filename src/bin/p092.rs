@@ -41,16 +41,18 @@ fn create_map(limit: u32) -> Vec<Option<bool>> {
     map
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let limit = 10000000;
     let mut map = create_map(limit);
-    (1..limit)
-        .filter(|&n| is_reach_89(n, &mut map))
-        .count()
-        .to_string()
+    (1..limit).filter(|&n| is_reach_89(n, &mut map)).count()
 }
 
-problem!("8581146", solve);
+problem!(
+    title: "Square Digit Chains",
+    difficulty: 5,
+    "sha256:cc81867f70c11c35:b85234a4e7fafbed40791a9f9fff790804f9ab04b4f5095c4dcf484e4cc64be3",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
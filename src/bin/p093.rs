@@ -170,7 +170,12 @@ fn solve() -> String {
     format!("{}{}{}{}", seq[0], seq[1], seq[2], seq[3])
 }
 
-problem!("1258", solve);
+problem!(
+    title: "Arithmetic Expressions",
+    difficulty: 35,
+    "sha256:e2191d92c327c6e8:78d79e7e60302f8b74093478e590bee436ba80bb3845c3cbdbe3dda3280d205f",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
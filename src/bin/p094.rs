@@ -55,7 +55,7 @@ use num_traits::ToPrimitive;
 // L <= 1000000000
 // k <= (100000000 - 4) / 6, (100000000 - 2) / 6
 
-fn solve() -> String {
+fn solve() -> u32 {
     let limit = 1000000000;
 
     PelRoots::<BigUint>::new(3)
@@ -78,7 +78,11 @@ fn solve() -> String {
         .map(|(a, b)| 2 * a + b)
         .take_while(|&side| side <= limit)
         .sum::<u32>()
-        .to_string()
 }
 
-problem!("518408346", solve);
+problem!(
+    title: "Almost Equilateral Triangles",
+    difficulty: 35,
+    "sha256:96b3021293fc599c:668dff3982a13aa953153988d69f7d63086ef8f38b88d18b08257cb77b911fea",
+    solve
+);
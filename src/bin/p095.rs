@@ -60,8 +60,12 @@ fn create_proper_divisor_map(limit: usize) -> Vec<usize> {
 
 fn compute(limit: usize) -> usize {
     let mut len_map = vec![None; limit + 1];
-    let div_map = create_proper_divisor_map(limit);
+    let div_map = {
+        let _phase = common::phase("divisors");
+        create_proper_divisor_map(limit)
+    };
 
+    let _phase = common::phase("chains");
     (1..len_map.len())
         .map(|n| (n, get_chain_len(n, &mut len_map, &div_map)))
         .max_by_key(|&(n, div)| (div, -(n as isize)))
@@ -69,8 +73,13 @@ fn compute(limit: usize) -> usize {
         .0
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve() -> usize {
+    compute(1000000)
 }
 
-problem!("14316", solve);
+problem!(
+    title: "Amicable Chains",
+    difficulty: 30,
+    "sha256:12617c25f128d99b:b7e8cac04be5a4e2503d5147135013fa107f97ae689b3adeeac1f4196dd4957f",
+    solve
+);
@@ -188,7 +188,7 @@ fn solve_sudoku(mut puzzle: SuDoku) -> Vec<SuDoku> {
     answers
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<usize> {
     let mut br = BufReader::new(file);
 
     let mut answers = Vec::new();
@@ -203,7 +203,13 @@ fn solve(file: File) -> io::Result<String> {
         sum += 100 * ans.get_at(0, 0) + 10 * ans.get_at(1, 0) + ans.get_at(2, 0);
     }
 
-    Ok(sum.to_string())
+    Ok(sum)
 }
 
-problem!("24702", "p096_sudoku.txt", solve);
+problem!(
+    title: "Su Doku",
+    difficulty: 25,
+    "sha256:f431007afec88d74:9da954631add9ac1e464f84ef5d04be0b129f77534149842de0b07d1c40dcbcf",
+    "p096_sudoku.txt",
+    solve
+);
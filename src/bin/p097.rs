@@ -40,7 +40,7 @@ fn add_unit(a: &BigUint, b: &BigUint, unit: &BigUint) -> BigUint {
     (a + b) % unit
 }
 
-fn solve() -> String {
+fn solve() -> BigUint {
     let unit: BigUint = FromPrimitive::from_u64(100_0000_0000).unwrap();
     add_unit(
         &mul_unit(
@@ -54,7 +54,12 @@ fn solve() -> String {
         ),
         &One::one(),
         &unit,
-    ).to_string()
+    )
 }
 
-problem!("8739992577", solve);
+problem!(
+    title: "Large Non-Mersenne Prime",
+    difficulty: 5,
+    "sha256:ecff06a02ddd60e2:ba4cb53fd8b6697da2aaa4bc0c9da417302501fdb64f62b6e106915c7fd6fadf",
+    solve
+);
@@ -174,7 +174,7 @@ fn max_square(groups: Vec<(u64, Vec<(Vec<u64>, Vec<u64>)>)>) -> u64 {
     max
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u64> {
     let words = read_words(file)?;
     let groups = get_anagram_groups(words);
     let pairs = flatten_groups(groups);
@@ -182,7 +182,13 @@ fn solve(file: File) -> io::Result<String> {
     let groups = group_by_len(indices);
     let max = max_square(groups);
 
-    Ok(max.to_string())
+    Ok(max)
 }
 
-problem!("18769", "p098_words.txt", solve);
+problem!(
+    title: "Anagramic Squares",
+    difficulty: 35,
+    "sha256:714f32a530f43546:856b25ca52a60fab8b4f8158a3b17629997d525a4a69bb95f25eeee42958672c",
+    "p098_words.txt",
+    solve
+);
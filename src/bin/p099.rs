@@ -16,7 +16,7 @@ use std::fs::File;
 use std::io::prelude::*;
 use std::io::{self, BufReader};
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mut max_val = 0.0;
     let mut max_idx = 0;
 
@@ -32,7 +32,13 @@ fn solve(file: File) -> io::Result<String> {
             max_idx = idx;
         }
     }
-    Ok(max_idx.to_string())
+    Ok(max_idx)
 }
 
-problem!("709", "p099_base_exp.txt", solve);
+problem!(
+    title: "Largest Exponential",
+    difficulty: 10,
+    "sha256:5af6c39b99b99ddd:a5d5234f1fbd070674372501e16c6a486fd45e6eff37d4961a6a056eff2e1ec3",
+    "p099_base_exp.txt",
+    solve
+);
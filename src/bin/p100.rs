@@ -40,12 +40,17 @@ fn compute(limit: BigUint) -> BigUint {
         .unwrap()
 }
 
-fn solve() -> String {
+fn solve() -> BigUint {
     let limit = "1000000000000".parse().unwrap();
-    compute(limit).to_string()
+    compute(limit)
 }
 
-problem!("756872327473", solve);
+problem!(
+    title: "Arranged Probability",
+    difficulty: 30,
+    "sha256:72823c4c8251780c:99b4fa4279a5aeb79eccde34586fa5dd82589668e89088c41c4ed54fe7740b7a",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
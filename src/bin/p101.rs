@@ -67,15 +67,19 @@ fn u_to_vec(dim: u32, f: fn(BigInt) -> BigInt) -> Vec<(BigInt, BigInt)> {
         }).collect()
 }
 
-fn solve() -> String {
+fn solve() -> BigInt {
     let un = u_to_vec(10, u);
     (0..10)
         .map(|i| bop(&un[..i + 1]))
         .fold(num_traits::zero::<BigInt>(), |acc, elt| acc + elt)
-        .to_string()
 }
 
-problem!("37076114526", solve);
+problem!(
+    title: "Optimum Polynomial",
+    difficulty: 35,
+    "sha256:901614aa872c2574:0c0615164b4e1b97d645a315411b350f1b68d9711b4e345b25f9ded82666db54",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -67,7 +67,7 @@ impl Line {
 
 impl Triangle {
     fn contains(self, p: Point) -> bool {
-        use Side::{C, L, R};
+        use self::Side::{C, L, R};
         let s0 = Line(self.0, self.1).side(p);
         let s1 = Line(self.1, self.2).side(p);
         let s2 = Line(self.2, self.0).side(p);
@@ -92,7 +92,7 @@ impl Triangle {
     }
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let origin = Point(0, 0);
 
     let mut cnt = 0;
@@ -112,10 +112,16 @@ fn solve(file: File) -> io::Result<String> {
         }
     }
 
-    Ok(cnt.to_string())
+    Ok(cnt)
 }
 
-problem!("228", "p102_triangles.txt", solve);
+problem!(
+    title: "Triangle Containment",
+    difficulty: 15,
+    "sha256:531f0a99d676a902:e794471b313e5321200854fcd245979052a4463e740f3b2a07b8d7c303f47618",
+    "p102_triangles.txt",
+    solve
+);
 
 #[cfg(test)]
 mod test {
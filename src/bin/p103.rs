@@ -162,7 +162,12 @@ fn solve() -> String {
         .concat()
 }
 
-problem!("20313839404245", solve);
+problem!(
+    title: "Special Subset Sums: Optimum",
+    difficulty: 45,
+    "sha256:cc7dea081f117599:bd26e2097d4273709978169668e2cffd11e37745bcd14f5ac601b4aa122c45d2",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
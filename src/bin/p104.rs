@@ -112,7 +112,7 @@ impl Iterator for FibLast {
     }
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let len = 9;
     let first = FibFirst::new(len);
     let last = FibLast::new(len);
@@ -122,10 +122,15 @@ fn solve() -> String {
         .enumerate()
         .find(|&(_, (f, l))| is_pandigit(f) && is_pandigit(l))
         .unwrap();
-    (k + 1).to_string()
+    k + 1
 }
 
-problem!("329468", solve);
+problem!(
+    title: "Pandigital Fibonacci Ends",
+    difficulty: 25,
+    "sha256:46e02ccaee36e1ae:1bc215acee45d6dfbe21bd3e8261356c42e86e0cff9df6c2173f140491a54c71",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
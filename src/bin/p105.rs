@@ -61,7 +61,7 @@ fn is_sss(nums: &mut [u32]) -> bool {
     true
 }
 
-fn solve(file: File) -> io::Result<String> {
+fn solve(file: File) -> io::Result<u32> {
     let mut sum = 0;
     for line in BufReader::new(file).lines() {
         let mut nums = line?
@@ -75,7 +75,13 @@ fn solve(file: File) -> io::Result<String> {
         }
     }
 
-    Ok(sum.to_string())
+    Ok(sum)
 }
 
-problem!("73702", "p105_sets.txt", solve);
+problem!(
+    title: "Special Subset Sums: Testing",
+    difficulty: 45,
+    "sha256:4390b0ef5adbd390:7db55f84c50587075be5c934315327ce400e8843e50ce036be0720a248b2b6b6",
+    "p105_sets.txt",
+    solve
+);
@@ -109,11 +109,16 @@ fn get_num_pairs(ps: &PrimeSet, n: u64) -> u64 {
         .sum()
 }
 
-fn solve() -> String {
-    get_num_pairs(&PrimeSet::new(), 12).to_string()
+fn solve() -> u64 {
+    get_num_pairs(&PrimeSet::new(), 12)
 }
 
-problem!("21384", solve);
+problem!(
+    title: "Special Subset Sums: Meta-testing",
+    difficulty: 50,
+    "sha256:045ecabdb83dc928:c959dad89147c12bb2cd7fcdee3e6900ac32df056662b16ad015e23bf7fa8607",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -13,6 +13,7 @@
 extern crate common;
 extern crate union_find;
 
+use common::Params;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{self, BufReader};
@@ -47,11 +48,18 @@ fn compute<R: Read>(r: R, size: usize) -> io::Result<usize> {
     Ok(saving)
 }
 
-fn solve(file: File) -> io::Result<String> {
-    Ok(compute(file, 40)?.to_string())
+fn solve(file: File, params: &Params) -> io::Result<usize> {
+    Ok(compute(file, params.get("size"))?)
 }
 
-problem!("259679", "p107_network.txt", solve);
+problem!(
+    title: "Minimal Network",
+    difficulty: 35,
+    "sha256:6afb69fcafaa7c99:9169fe3b2b655a36ddc7b08930ba1a0a252dc183dcad9f491ea6b115b79c107c",
+    "p107_network.txt",
+    solve,
+    size: usize = 40; min 1
+);
 
 #[cfg(test)]
 mod tests {
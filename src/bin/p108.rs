@@ -32,13 +32,18 @@ fn num_pairs(ps: &PrimeSet, n: u64) -> u64 {
     (prod - 1) / 2 + 1
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let n = 1000;
     let ps = PrimeSet::new();
-    (1..).find(|&i| num_pairs(&ps, i) > n).unwrap().to_string()
+    (1..).find(|&i| num_pairs(&ps, i) > n).unwrap()
 }
 
-problem!("180180", solve);
+problem!(
+    title: "Diophantine Reciprocals I",
+    difficulty: 30,
+    "sha256:d8b752835382be21:e048f2c500bce43d8f91fa68624f264f6a3d23be80fd38ce041ade21ce4474dc",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -56,11 +56,16 @@ fn count_way(score: u32) -> u32 {
         .fold(0, |i, &a| i + a)
 }
 
-fn solve() -> String {
-    count_way(100).to_string()
+fn solve() -> u32 {
+    count_way(100)
 }
 
-problem!("38182", solve);
+problem!(
+    title: "Darts",
+    difficulty: 45,
+    "sha256:9c5f86aaa5d4a08f:b270cde633b9ea5ae9b1f452b199aa484db57fecb04ead071589b1a1f418111c",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -58,11 +58,16 @@ fn compute(limit: u64) -> u64 {
     }
 }
 
-fn solve() -> String {
-    compute(4000000).to_string()
+fn solve() -> u64 {
+    compute(4000000)
 }
 
-problem!("9350130049860600", solve);
+problem!(
+    title: "Diophantine Reciprocals II",
+    difficulty: 40,
+    "sha256:f14e43a9f9dad7d8:1e352a34248d38f20867da3d673b386eb85049be4c1fa0aa199b5379fa6b2021",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
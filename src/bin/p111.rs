@@ -126,17 +126,19 @@ fn compute_s(ps: &PrimeSet, n: usize, d: u64) -> (usize, usize, u64) {
     (0, 0, 0)
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let n = 10;
     let ps = PrimeSet::new();
 
-    (0u64..10)
-        .map(|d| compute_s(&ps, n, d).2)
-        .sum::<u64>()
-        .to_string()
+    (0u64..10).map(|d| compute_s(&ps, n, d).2).sum::<u64>()
 }
 
-problem!("612407567715", solve);
+problem!(
+    title: "Primes with Runs",
+    difficulty: 45,
+    "sha256:ac8eb67236b183b9:7e09f575e9667be160b13befd23162763e8d64d58c6bd9b7e69c1cf136fd2515",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -78,11 +78,16 @@ fn compute(percent: u32) -> u32 {
     unreachable!()
 }
 
-fn solve() -> String {
-    compute(99).to_string()
+fn solve() -> u32 {
+    compute(99)
 }
 
-problem!("1587000", solve);
+problem!(
+    title: "Bouncy Numbers",
+    difficulty: 15,
+    "sha256:9bd26f16de2aa4d7:b1ac1e1eb09791408db618ffd75f6b65333e79defdfdbd1bb77df09abef4d7db",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
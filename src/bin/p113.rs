@@ -56,11 +56,16 @@ fn num_nonbouncy(len: usize) -> u64 {
     num_incr + num_decr - num_incr_and_decr
 }
 
-fn solve() -> String {
-    num_nonbouncy(100).to_string()
+fn solve() -> u64 {
+    num_nonbouncy(100)
 }
 
-problem!("51161058134250", solve);
+problem!(
+    title: "Non-bouncy Numbers",
+    difficulty: 30,
+    "sha256:0f2b55ef65ad31ad:45fc8b2d9c5cace13636a711195cd9d68831ba8d20579b08f57884860a66ca37",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
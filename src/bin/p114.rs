@@ -43,12 +43,18 @@ fn get_cnt((n, m): (u32, u32), map: &mut HashMap<(u32, u32), u64>) -> u64 {
     sum
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let mut map = HashMap::new();
-    get_cnt((50, 3), &mut map).to_string()
+    get_cnt((50, 3), &mut map)
 }
 
-problem!("16475640049", solve);
+problem!(
+    title: "Counting Block Combinations I",
+    difficulty: 35,
+    tags: ["dp"],
+    "sha256:7d441a09644eacad:2d2ab697c1411d0b5434af5530ab5715da7f141c9f6e5d881fa9cf2037b003d2",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
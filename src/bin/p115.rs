@@ -43,16 +43,21 @@ fn get_cnt(n: usize, m: usize, map: &mut HashMap<(usize, usize), usize>) -> usiz
     sum
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let mut map = HashMap::new();
     (1..)
         .filter(|&n| get_cnt(n, 50, &mut map) > 1000000)
         .next()
         .unwrap()
-        .to_string()
 }
 
-problem!("168", solve);
+problem!(
+    title: "Counting Block Combinations II",
+    difficulty: 35,
+    tags: ["dp"],
+    "sha256:aaf28452998f47e7:8e36acdacf8874acf91d96e2273151e39ee32c48aa1aab9f5abe058fc9d950ad",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
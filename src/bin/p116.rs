@@ -47,12 +47,18 @@ fn count_all(len: u64, map: &mut HashMap<(u64, u64), u64>) -> u64 {
     count_red(len, map) + count_green(len, map) + count_blue(len, map)
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let mut map = HashMap::new();
-    count_all(50, &mut map).to_string()
+    count_all(50, &mut map)
 }
 
-problem!("20492570929", solve);
+problem!(
+    title: "Red, Green or Blue Tiles",
+    difficulty: 30,
+    tags: ["dp"],
+    "sha256:e2decfd3c352d0fa:9bd2a11606a9fa2a042174d21606d9a61dbd922c3714f563a62af7f0371c8d36",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
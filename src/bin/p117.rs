@@ -37,12 +37,18 @@ fn count(len: u32, map: &mut HashMap<u32, u64>) -> u64 {
     sum
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let mut map = HashMap::new();
-    count(50, &mut map).to_string()
+    count(50, &mut map)
 }
 
-problem!("100808458960497", solve);
+problem!(
+    title: "Red, Green, and Blue Tiles",
+    difficulty: 35,
+    tags: ["dp"],
+    "sha256:fa6f54cd2839bf37:77dc8da7009616e0f6c7c913e442cd101815dc52cb3d2da7080d06dff13a2e1f",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
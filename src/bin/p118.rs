@@ -109,13 +109,18 @@ fn count_primes(ps: &PrimeSet, digits: &[u64]) -> usize {
     cnt
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let digits = (1..10).collect::<Vec<_>>();
     let ps = PrimeSet::new();
-    count_primes(&ps, &digits).to_string()
+    count_primes(&ps, &digits)
 }
 
-problem!("44680", solve);
+problem!(
+    title: "Pandigital Prime Sets",
+    difficulty: 50,
+    "sha256:88220275db311b5c:caaa5073887ace5a6a6b319877b98730fbddc25c29c6220138e1d8fe64a3a967",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -78,12 +78,17 @@ fn compute_a(n: usize) -> (u64, u64, u32) {
         .unwrap()
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let (n, _b, _e) = compute_a(30);
-    n.to_string()
+    n
 }
 
-problem!("248155780267521", solve);
+problem!(
+    title: "Digit Power Sum",
+    difficulty: 30,
+    "sha256:cac1b82e4bc63592:5cf03878af771c8bc847c3dec109e0bb89eabf8a9f92a41c50b2fc56d9343f91",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -56,11 +56,16 @@ fn rmax(a: u32) -> u32 {
     }
 }
 
-fn solve() -> String {
-    (3..1001).map(rmax).sum::<u32>().to_string()
+fn solve() -> u32 {
+    (3..1001).map(rmax).sum::<u32>()
 }
 
-problem!("333082500", solve);
+problem!(
+    title: "Square Remainders",
+    difficulty: 25,
+    "sha256:24532da7a531fd7e:e741163821771c2aa08e60739d9ae94df7b7edd092861e5d5377a0e504f51320",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
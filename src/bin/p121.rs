@@ -62,12 +62,17 @@ fn max_prize<T: Integer + Clone>(p: Ratio<T>) -> T {
     p.denom().div_floor(p.numer())
 }
 
-fn solve() -> String {
+fn solve() -> BigUint {
     let prob = probability_of_player_win::<BigUint>(15);
-    max_prize(prob).to_string()
+    max_prize(prob)
 }
 
-problem!("2269", solve);
+problem!(
+    title: "Disc Game Prize Fund",
+    difficulty: 35,
+    "sha256:3b59f0d441ff4e66:eebfa23abcac79a8f2cc6fc97ff1e336ed95d84340829c5eefaee92983005b62",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -12,6 +12,7 @@
 #[macro_use(problem)]
 extern crate common;
 
+use common::Params;
 use std::u32;
 
 fn backtrack(power: u32, depth: u32, limit: u32, cost: &mut [u32], path: &mut [u32]) {
@@ -36,15 +37,20 @@ fn compute_cost(limit: u32) -> Vec<u32> {
     cost
 }
 
-fn solve() -> String {
-    let limit = 200;
+fn solve(params: &Params) -> u32 {
+    let limit = params.get::<u32>("limit");
     compute_cost(limit)[1..(limit as usize) + 1]
         .iter()
         .fold(0, |x, &y| x + y)
-        .to_string()
 }
 
-problem!("1582", solve);
+problem!(
+    title: "Efficient Exponentiation",
+    difficulty: 40,
+    "sha256:0e99e71dbf084007:749e48b8a4fcc2d3df0ce1b639f5245d679a2715e9b90dccd4c55d36b93706e4",
+    solve,
+    limit: u32 = 200; min 1
+);
 
 #[cfg(test)]
 mod tests {
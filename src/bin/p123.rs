@@ -42,11 +42,16 @@ fn compute(limit: u64) -> u64 {
         .0
 }
 
-fn solve() -> String {
-    compute(10u64.pow(10)).to_string()
+fn solve() -> u64 {
+    compute(10u64.pow(10))
 }
 
-problem!("21035", solve);
+problem!(
+    title: "Prime Square Remainders",
+    difficulty: 30,
+    "sha256:e71d7e02a0a81d5e:a949c76cc100f8724a1f1065824abdf40e3fa5ff7d10406d11dbab043acc2002",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -146,7 +146,7 @@ impl Iterator for RadValues {
     }
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     const LIMIT: u64 = 100000;
     let index = 10000;
 
@@ -155,10 +155,14 @@ fn solve() -> String {
         .flat_map(|(base, facts)| Multiples::new(base, facts).take_while(|&n| n <= LIMIT))
         .nth(index - 1)
         .unwrap()
-        .to_string()
 }
 
-problem!("21417", solve);
+problem!(
+    title: "Ordered Radicals",
+    difficulty: 25,
+    "sha256:82a5534e955a924b:d2f7b46c95a7b9a2b7ebd1b774c645fb78567ecb468d2908c27d795300d9f0e3",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
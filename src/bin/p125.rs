@@ -40,13 +40,18 @@ fn palindromic_sum_set(limit: u32) -> HashSet<u32> {
     set
 }
 
-fn solve() -> String {
+fn solve() -> u32 {
     let limit = 10u32.pow(8);
     let set = palindromic_sum_set(limit);
-    set.iter().fold(0, |x, &y| x + y).to_string()
+    set.iter().fold(0, |x, &y| x + y)
 }
 
-problem!("2906969179", solve);
+problem!(
+    title: "Palindromic Sums",
+    difficulty: 25,
+    "sha256:552e2bbcf8958f5a:f0b20a33ef6c3c762de8b4ca9c0e3caafa6e42a412253de3c6fad9c06a3f6466",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
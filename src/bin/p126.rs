@@ -53,11 +53,16 @@ fn compute(sum: u32) -> u32 {
     cnt.iter().position(|&n| n == sum).unwrap() as u32
 }
 
-fn solve() -> String {
-    compute(1000).to_string()
+fn solve() -> u32 {
+    compute(1000)
 }
 
-problem!("18522", solve);
+problem!(
+    title: "Cuboid Layers",
+    difficulty: 55,
+    "sha256:03a7ff49aff39fd9:8dde0d79175343a7f0330f982022646b95fe8650bd135d0351e86f11e28beba9",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
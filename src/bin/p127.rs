@@ -100,11 +100,16 @@ fn abc_hits_c_sum(c_limit: u64) -> u64 {
     c_sum
 }
 
-fn solve() -> String {
-    abc_hits_c_sum(120000).to_string()
+fn solve() -> u64 {
+    abc_hits_c_sum(120000)
 }
 
-problem!("18407904", solve);
+problem!(
+    title: "abc-hits",
+    difficulty: 50,
+    "sha256:5db817483887a03b:50dfab7701a7223e47f5abc9799239b2205d5fe056cf0eab50a56b31c81146b8",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -242,11 +242,16 @@ impl Iterator for Pd3Nums {
     }
 }
 
-fn solve() -> String {
-    Pd3Nums::new().nth(2000 - 1).unwrap().to_string()
+fn solve() -> u64 {
+    Pd3Nums::new().nth(2000 - 1).unwrap()
 }
 
-problem!("14516824220", solve);
+problem!(
+    title: "Hexagonal Tile Differences",
+    difficulty: 55,
+    "sha256:98012b6f0bc4dff9:6f13e9314a25798cc6a5b8777c488f7dd2814490f5de8f0136e206fad5665402",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -35,7 +35,7 @@ fn a(n: u64) -> u64 {
     }
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let limit = 1000001;
 
     (limit..)
@@ -43,10 +43,14 @@ fn solve() -> String {
         .filter(|&n| !n.is_multiple_of(&5))
         .find(|&n| a(n) >= limit)
         .unwrap()
-        .to_string()
 }
 
-problem!("1000023", solve);
+problem!(
+    title: "Repunit Divisibility",
+    difficulty: 45,
+    "sha256:d33050d4ed68dae5:8e0e1aacca86199f173e23c3a6c36da0881b0ef84b6cf4ebc6fc513b1414c460",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
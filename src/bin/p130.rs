@@ -37,7 +37,7 @@ fn a(n: u64) -> u64 {
     }
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let ps = PrimeSet::new();
     (3..)
         .step_by(2)
@@ -46,10 +46,14 @@ fn solve() -> String {
         .filter(|&n| (n - 1).is_multiple_of(&a(n)))
         .take(25)
         .sum::<u64>()
-        .to_string()
 }
 
-problem!("149253", solve);
+problem!(
+    title: "Composites with Prime Repunit Property",
+    difficulty: 45,
+    "sha256:02e209c3b2fd321d:ed3d9783476cb5a7224406feb63e9d271447f04800020565b32964a25db7463a",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -109,11 +109,16 @@ fn compute(limit: u64) -> usize {
         .count()
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve() -> usize {
+    compute(1000000)
 }
 
-problem!("173", solve);
+problem!(
+    title: "Prime Cube Partnership",
+    difficulty: 20,
+    "sha256:539d76f5684b9bda:47c48cbdc9b599ce7fed7dc91fd23207957004cd8eadc5799355504df671ac1d",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
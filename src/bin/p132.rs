@@ -35,13 +35,17 @@ extern crate prime;
 use integer::Integer;
 use prime::PrimeSet;
 
-pub fn solve() -> String {
+pub fn solve() -> u64 {
     PrimeSet::new()
         .iter()
         .filter(|&p| 10.mod_pow(&10u64.pow(9), &(9 * p)) == 1)
         .take(40)
         .sum::<u64>()
-        .to_string()
 }
 
-problem!("843296", solve);
+problem!(
+    title: "Large Repunit Factors",
+    difficulty: 45,
+    "sha256:1b92752856e9c2e6:1b8f5709cc4002386f7d130308eb599985adb81d6834450440d03b8f50c78882",
+    solve
+);
@@ -77,7 +77,7 @@ fn a(n: u64) -> u64 {
     }
 }
 
-fn solve() -> String {
+fn solve() -> u64 {
     let ps = PrimeSet::new();
 
     let sum = ps
@@ -87,7 +87,12 @@ fn solve() -> String {
         .filter(|&p| a(p).factorize(&ps).any(|(b, _e)| b != 2 && b != 5))
         .sum::<u64>();
 
-    (sum + 2 + 3 + 5).to_string()
+    sum + 2 + 3 + 5
 }
 
-problem!("453647705", solve);
+problem!(
+    title: "Repunit Nonfactors",
+    difficulty: 50,
+    "sha256:cd438a9e504db874:5f7d1c344bc64fe09a7eb99393b01c2e07eb48aedaac2ce5211bb9e973f5ce73",
+    solve
+);
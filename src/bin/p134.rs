@@ -101,7 +101,7 @@ extern crate prime;
 use num_integer::Integer;
 use prime::PrimeSet;
 
-fn solve() -> String {
+fn solve() -> u64 {
     let min_limit = 5;
     let max_limit = 1000000;
 
@@ -138,7 +138,12 @@ fn solve() -> String {
         }
         sum += b;
     }
-    sum.to_string()
+    sum
 }
 
-problem!("18613426663617118", solve);
+problem!(
+    title: "Prime Pair Connection",
+    difficulty: 45,
+    "sha256:30f7eb5a06510a54:f725067d013131224646fae6fbb571da69ce7eee90d9a9e2f826a515e063b992",
+    solve
+);
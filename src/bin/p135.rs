@@ -93,17 +93,18 @@ fn num_solutions(limit: usize) -> Vec<u32> {
     cnt
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let limit = 1000000;
     let cnt = 10;
-    num_solutions(limit)
-        .iter()
-        .filter(|&&n| n == cnt)
-        .count()
-        .to_string()
+    num_solutions(limit).iter().filter(|&&n| n == cnt).count()
 }
 
-problem!("4989", solve);
+problem!(
+    title: "Same Differences",
+    difficulty: 45,
+    "sha256:d03b0e98486c2cd4:aace7832e5cdebfd3c942649a5a8b493ec62fbc4ddc2da53118962c3814438d7",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
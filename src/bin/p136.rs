@@ -32,17 +32,18 @@ fn num_solutions(limit: usize) -> Vec<u32> {
     cnt
 }
 
-fn solve() -> String {
+fn solve() -> usize {
     let limit = 50000000;
     let cnt = 1;
-    num_solutions(limit)
-        .iter()
-        .filter(|&&n| n == cnt)
-        .count()
-        .to_string()
+    num_solutions(limit).iter().filter(|&&n| n == cnt).count()
 }
 
-problem!("2544559", solve);
+problem!(
+    title: "Singleton Difference",
+    difficulty: 50,
+    "sha256:d5b5cd36efed7fae:1a90c58243684964877dbae58407691936edb308843b70b7405a9f774df7159d",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
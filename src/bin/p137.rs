@@ -63,11 +63,16 @@ fn compute(i: usize) -> u64 {
     .0
 }
 
-fn solve() -> String {
-    compute(14).to_string()
+fn solve() -> u64 {
+    compute(14)
 }
 
-problem!("1120149658760", solve);
+problem!(
+    title: "Fibonacci Golden Nuggets",
+    difficulty: 50,
+    "sha256:c3074c083e310700:b97e11949ec2a9fc0980e0556c721cfef205e1d5da5a3e6603d91e54ac1f9932",
+    solve
+);
 
 #[cfg(test)]
 mod tests {
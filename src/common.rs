@@ -11,50 +11,148 @@ extern crate failure;
 #[macro_use]
 extern crate failure_derive;
 extern crate getopts;
+#[macro_use]
+extern crate lazy_static;
+extern crate libc;
+extern crate num_bigint;
 extern crate num_integer;
+extern crate num_rational;
 extern crate reqwest;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+extern crate sha2;
 extern crate term;
 extern crate time;
 
+use bench::bench;
 use getopts::Options;
 use num_integer::Integer;
 use serde::Serialize;
 use std::borrow::Cow;
-use std::fs::{self, File};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
 use std::io::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{env, fmt, io, process};
 use term::color;
 use term::color::Color;
 
+pub use answer::{
+    answer_matches, display_answer, hash_answer, is_hashed_answer, new_salt, Answer, AnswerType,
+    Hidden, UNKNOWN_ANSWER,
+};
+pub use baseline::{
+    baseline_path, find_regressions, host_difference, Change, Regression, DEFAULT_REGRESSION_RATIO,
+};
+pub use bench::{BenchConfig, BenchStats};
+pub use config::{set_color, use_color, Budgets, ColorChoice, TimeLimits, CONFIG_FILE};
+pub use error::{Error, ErrorInfo, ErrorKind, ErrorOutput};
+pub use gen::{generate, generated_files};
+pub use memory::{format_bytes, peak_rss, AllocStats, CountingAlloc, MemoryStats};
+pub use params::Params;
+pub use phase::{enable_phases, phase, Phase, PhaseGuard};
+pub use record::{load_records, save_records, update_records, Record, LAST_RESULTS};
+pub use report::{write_html, write_report, Format};
+pub use resource::{
+    Backoff, FetchError, FileFetcher, FileInfo, FileState, HttpFetcher, MemoryFetcher,
+    ResourceFetcher, Resources,
+};
+pub use scaffold::problem_source;
+pub use schema::{Expected, Host, RunInfo, SCHEMA_VERSION};
+pub use select::{parse_duration, Selection};
+pub use unwind::{catch_panic, CaughtPanic};
+
+mod answer;
+mod baseline;
+mod bench;
+mod config;
+mod error;
+mod gen;
+mod memory;
+mod params;
+mod phase;
+mod record;
+mod report;
+mod resource;
+mod scaffold;
+mod schema;
+mod select;
+mod unwind;
+
 type OutputPair<'a> = (Option<Color>, Cow<'a, str>);
 
 const NSEC_PER_SEC: u64 = 1000000000;
-const NSEC_WARN_LIMIT: u64 = 1 * NSEC_PER_SEC;
-const NSEC_NG_LIMIT: u64 = 10 * NSEC_PER_SEC;
+
+const PHASE_NAME_WIDTH: usize = 16;
 
 const COLOR_OK: Color = color::GREEN;
 const COLOR_NG: Color = color::RED;
 const COLOR_WARN: Color = color::YELLOW;
 
-#[derive(Fail, Debug, Clone)]
-#[fail(display = "{}, {}", status, body)]
-struct InvalidHttpStatusError {
-    status: reqwest::StatusCode,
-    body: String,
-}
+pub type Result<T> = std::result::Result<T, Error>;
 
-pub type Result<T> = std::result::Result<T, failure::Error>;
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SolverResult<T> {
+    /// Version of the JSON shape, 1 if it was saved before it had one.
+    #[serde(default = "schema::legacy_schema")]
+    pub schema: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Expected>,
+    /// Timestamps, host and revision of the run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<RunInfo>,
     pub time: u64,
     pub answer: T,
     pub is_ok: bool,
+    /// The answer was not compared with the expected one, as it is only known
+    /// for the default parameters. `is_ok` is true then.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub unchecked: bool,
+    /// The answer is right but took longer than the time budget.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub slow: bool,
+    /// Statistics of repeated runs in benchmark mode. `time` is their median.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    /// Memory used by the run, or by the last measured run in benchmark mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+    /// Phases timed with `phase`, of the last run in benchmark mode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<Phase>,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+impl<T> SolverResult<T> {
+    /// Returns the result with its answer replaced, for output that must
+    /// not spoil it.
+    pub fn without_answer(&self) -> SolverResult<Hidden> {
+        SolverResult {
+            schema: self.schema,
+            id: self.id,
+            expected: self.expected,
+            run: self.run.clone(),
+            time: self.time,
+            answer: Hidden,
+            is_ok: self.is_ok,
+            unchecked: self.unchecked,
+            slow: self.slow,
+            bench: self.bench.clone(),
+            memory: self.memory,
+            phases: self.phases.clone(),
+        }
+    }
 }
 
 impl<T: Serialize> SolverResult<T> {
@@ -64,15 +162,31 @@ impl<T: Serialize> SolverResult<T> {
     }
 }
 
+fn format_time(nsec: u64) -> String {
+    let (sec, nsec) = nsec.div_rem(&NSEC_PER_SEC);
+    format!("{:3}.{:09}", sec, nsec)
+}
+
 fn print_items(items: &[OutputPair]) {
     match term::stdout() {
-        None => {
+        _ if !use_color() => {
             let mut out = io::stdout();
             for &(_, ref s) in items {
                 let _ = write!(&mut out, "{}", s);
             }
             let _ = out.flush();
         }
+        // Colors were asked for, but there is no terminfo to tell how.
+        None => {
+            let mut out = io::stdout();
+            for &(c, ref s) in items {
+                let _ = match c {
+                    Some(c) => write!(&mut out, "\x1b[3{}m{}\x1b[0m", c, s),
+                    None => write!(&mut out, "{}", s),
+                };
+            }
+            let _ = out.flush();
+        }
         Some(mut t) => {
             for &(c, ref s) in items {
                 match c {
@@ -91,217 +205,1112 @@ fn print_items(items: &[OutputPair]) {
     }
 }
 
+/// Outcome of running a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Status {
+    /// The answer matches the expected one.
+    Ok,
+    /// The answer differs from the expected one.
+    Ng,
+    /// The solver was killed after running too long.
+    Timeout,
+    /// The solver ran out of its memory limit.
+    OutOfMemory,
+    /// There is no expected answer for the parameters used.
+    Unchecked,
+    /// The solver panicked.
+    Panicked,
+    /// The answer is right but took longer than the time budget.
+    Slow,
+}
+
+impl Status {
+    /// Returns the label shown in pretty output.
+    pub fn label(&self) -> &'static str {
+        match *self {
+            Status::Ok => "OK",
+            Status::Ng => "NG",
+            Status::Timeout => "TIMEOUT",
+            Status::OutOfMemory => "OOM",
+            Status::Unchecked => "UNCHECKED",
+            Status::Panicked => "PANIC",
+            Status::Slow => "SLOW",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match *self {
+            Status::Ok => COLOR_OK,
+            Status::Ng | Status::Timeout | Status::OutOfMemory | Status::Panicked => COLOR_NG,
+            Status::Unchecked | Status::Slow => COLOR_WARN,
+        }
+    }
+}
+
+fn normal<'a, T: Into<Cow<'a, str>>>(s: T) -> OutputPair<'a> {
+    (None, s.into())
+}
+fn colored<'a, T: Into<Cow<'a, str>>>(c: Color, s: T) -> OutputPair<'a> {
+    (Some(c), s.into())
+}
+
+fn status_items<'a>(
+    name: &str,
+    label: &'static str,
+    color: Color,
+    time: u64,
+    memory: Option<u64>,
+    answer: String,
+    limits: Option<TimeLimits>,
+) -> Vec<OutputPair<'a>> {
+    let mut items = vec![];
+    items.push(normal(format!("{} ", name)));
+
+    items.push(normal("["));
+    items.push(colored(color, label));
+    items.push(normal("] "));
+
+    let time_str = format!("{} ", format_time(time));
+    match limits {
+        Some(limits) if time > limits.ng => items.push(colored(COLOR_NG, time_str)),
+        Some(limits) if time >= limits.warn => items.push(colored(COLOR_WARN, time_str)),
+        _ => items.push(normal(time_str)),
+    }
+
+    let memory_str = memory.map(format_bytes).unwrap_or_default();
+    items.push(normal(format!("{:>10} ", memory_str)));
+
+    items.push(normal(format!("{} ", answer)));
+
+    items.push(normal("\n"));
+    items
+}
+
+/// Adds a line for each of `phases` and their children, indented by depth.
+fn push_phase_items(items: &mut Vec<OutputPair>, phases: &[Phase], indent: usize, depth: usize) {
+    for phase in phases {
+        let count = if phase.count > 1 {
+            format!(" x{}", phase.count)
+        } else {
+            String::new()
+        };
+        items.push(normal(format!(
+            "{:indent$}{:width$} {}{}\n",
+            "",
+            phase.name,
+            format_time(phase.time),
+            count,
+            indent = indent + 2 * depth,
+            width = PHASE_NAME_WIDTH.saturating_sub(2 * depth)
+        )));
+        push_phase_items(items, &phase.children, indent, depth + 1);
+    }
+}
+
+/// Prints a result line in the same format as `SolverResult::print_pretty`.
+pub fn print_status(
+    name: &str,
+    status: Status,
+    time: u64,
+    answer: &str,
+    limits: Option<TimeLimits>,
+) -> io::Result<()> {
+    print_items(&status_items(
+        name,
+        status.label(),
+        status.color(),
+        time,
+        None,
+        answer.to_string(),
+        limits,
+    ));
+    Ok(())
+}
+
+/// Prints a result line for a problem that failed with `error`, labelled
+/// with the kind of the error.
+pub fn print_error(
+    name: &str,
+    error: &ErrorInfo,
+    time: u64,
+    limits: Option<TimeLimits>,
+) -> io::Result<()> {
+    print_items(&status_items(
+        name,
+        error.kind.label(),
+        COLOR_NG,
+        time,
+        None,
+        error.message.clone(),
+        limits,
+    ));
+    Ok(())
+}
+
 impl<T: fmt::Display> SolverResult<T> {
-    pub fn print_pretty(&self, name: &str, enable_time_color: bool) -> io::Result<()> {
-        let mut items = vec![];
-        if self.is_ok {
-            items.push(normal(format!("{} ", name)));
+    pub fn status(&self) -> Status {
+        if self.unchecked {
+            Status::Unchecked
+        } else if self.slow {
+            Status::Slow
+        } else if self.is_ok {
+            Status::Ok
         } else {
-            items.push(normal(format!("{} ", name)));
+            Status::Ng
         }
+    }
 
-        items.push(normal("["));
+    /// Returns the error the result fails with: `WrongAnswer` if the answer
+    /// is not the expected one.
+    pub fn error(&self) -> Option<Error> {
         if self.is_ok {
-            items.push(ok("OK"));
+            None
         } else {
-            items.push(ng("NG"));
+            Some(Error::WrongAnswer)
         }
-        items.push(normal("] "));
+    }
 
-        let (sec, nsec) = self.time.div_rem(&NSEC_PER_SEC);
-        let time_str = format!("{:3}.{:09} ", sec, nsec);
-        if !enable_time_color || self.time < NSEC_WARN_LIMIT {
-            items.push(normal(time_str));
-        } else if self.time < NSEC_NG_LIMIT {
-            items.push(warn(time_str));
-        } else {
-            items.push(ng(time_str));
-        }
+    /// Marks the result slow if it is right but took longer than `budget`.
+    pub fn apply_budget(&mut self, budget: Option<u64>) {
+        self.slow = self.status() == Status::Ok && budget.map_or(false, |b| self.time > b);
+    }
 
-        items.push(normal(format!("{} ", self.answer)));
+    /// Returns the exit code of a solver binary giving this result: that of
+    /// its error, `Slow` if it is over its budget, or 0.
+    pub fn exit_code(&self) -> i32 {
+        match self.error() {
+            Some(err) => err.kind().exit_code(),
+            None if self.slow => ErrorKind::Slow.exit_code(),
+            None => 0,
+        }
+    }
 
-        items.push(normal("\n"));
-        print_items(&items);
+    /// Prints the result, coloring the time by `limits` if given.
+    pub fn print_pretty(&self, name: &str, limits: Option<TimeLimits>) -> io::Result<()> {
+        let status = self.status();
+        let mut items = status_items(
+            name,
+            status.label(),
+            status.color(),
+            self.time,
+            self.memory.map(|m| m.peak()),
+            self.answer.to_string(),
+            limits,
+        );
+        let indent = name.len() + status.label().len() + 4;
 
-        fn normal<'a, T: Into<Cow<'a, str>>>(s: T) -> OutputPair<'a> {
-            (None, s.into())
+        if let Some(ref stats) = self.bench {
+            items.push(normal(format!(
+                "{:width$}min {} mean {} stddev {} outliers {}/{}\n",
+                "",
+                format_time(stats.min),
+                format_time(stats.mean),
+                format_time(stats.stddev),
+                stats.outliers,
+                stats.runs,
+                width = indent
+            )));
         }
-        fn ok<'a, T: Into<Cow<'a, str>>>(s: T) -> OutputPair<'a> {
-            (Some(COLOR_OK), s.into())
-        }
-        fn warn<'a, T: Into<Cow<'a, str>>>(s: T) -> OutputPair<'a> {
-            (Some(COLOR_WARN), s.into())
-        }
-        fn ng<'a, T: Into<Cow<'a, str>>>(s: T) -> OutputPair<'a> {
-            (Some(COLOR_NG), s.into())
+
+        push_phase_items(&mut items, &self.phases, indent, 0);
+
+        if let Some(allocs) = self.memory.and_then(|m| m.allocs) {
+            items.push(normal(format!(
+                "{:width$}allocs {} ({} total) peak RSS {}\n",
+                "",
+                allocs.count,
+                format_bytes(allocs.bytes),
+                format_bytes(self.memory.unwrap().peak_rss),
+                width = indent
+            )));
         }
 
+        print_items(&items);
         Ok(())
     }
 }
 
-enum SolverFn<'a> {
-    FnOnly(fn() -> String),
-    FnWithFile(&'a str, fn(File) -> io::Result<String>),
+/// Metadata given to `problem!` as `title: "..", difficulty: N, tags: [..]`.
+#[derive(Debug)]
+pub struct Meta {
+    pub title: &'static str,
+    /// Difficulty rating on Project Euler, in percent.
+    pub difficulty: Option<u32>,
+    /// Tags that cannot be told from the source, such as `dp`.
+    pub tags: &'static [&'static str],
+}
+
+impl Meta {
+    /// Metadata of a problem that does not give any.
+    pub const NONE: Meta = Meta {
+        title: "",
+        difficulty: None,
+        tags: &[],
+    };
+}
+
+/// A problem registered by `problem!`.
+#[derive(Copy, Clone)]
+pub struct Problem {
+    pub id: u32,
+    pub solver: fn() -> Solver<'static>,
+    pub meta: &'static Meta,
+    /// `file` for problems reading a data file, `bigint` for problems using
+    /// big integers, plus the `pkg/` crates used.
+    pub tags: &'static [&'static str],
+    /// The `pkg/` crates used.
+    pub crates: &'static [&'static str],
+}
+
+impl Problem {
+    /// Returns the name of the problem's binary, e.g. `p001`.
+    pub fn name(&self) -> String {
+        format!("p{:03}", self.id)
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag) || self.meta.tags.contains(&tag)
+    }
+
+    /// Returns the tags found in the source and those given to `problem!`.
+    pub fn all_tags(&self) -> Vec<&'static str> {
+        let mut tags = self.tags.to_vec();
+        tags.extend_from_slice(self.meta.tags);
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    pub fn info(&self) -> ProblemInfo {
+        let solver = (self.solver)();
+        ProblemInfo {
+            id: self.id,
+            name: self.name(),
+            title: self.meta.title,
+            difficulty: self.meta.difficulty,
+            url: format!("https://projecteuler.net/problem={}", self.id),
+            tags: self.all_tags(),
+            files: solver.file_name().into_iter().collect(),
+            crates: self.crates,
+            params: solver
+                .params()
+                .defaults()
+                .into_iter()
+                .map(|(name, default)| (name, default.to_string()))
+                .collect(),
+        }
+    }
+}
+
+/// What `euler list` and `euler info` show about a problem.
+#[derive(Debug, Serialize)]
+pub struct ProblemInfo {
+    pub id: u32,
+    pub name: String,
+    pub title: &'static str,
+    /// Difficulty rating on Project Euler, in percent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<u32>,
+    pub url: String,
+    pub tags: Vec<&'static str>,
+    /// Data files read by the solver.
+    pub files: Vec<&'static str>,
+    pub crates: &'static [&'static str],
+    /// Parameters of the solver with their defaults.
+    pub params: BTreeMap<&'static str, String>,
 }
 
+/// A solver with its answer type erased. Takes the data file if the solver
+/// reads one and the expected answer, and returns the time taken, the answer
+/// and whether it matches the expected one.
+type RunFn<'a> = Box<dyn Fn(Option<File>, &Params, &str) -> Result<(u64, Answer, bool)> + 'a>;
+
+/// Name of the variant given to the constructors of `Solver`, unless it is
+/// renamed by `Solver::named`.
+const DEFAULT_VARIANT: &'static str = "default";
+
 pub struct Solver<'a> {
     answer: &'a str,
-    solver: SolverFn<'a>,
+    file_name: Option<&'a str>,
+    /// Ways to solve the problem, which must all give the same answer.
+    variants: Vec<(&'a str, RunFn<'a>)>,
+    /// Index of the variant run by `solve_with` and `bench_with`.
+    variant: usize,
+    params: Params,
+    /// Data file read in place of `file_name`.
+    input: Option<PathBuf>,
+    /// Answer expected in place of `answer`.
+    expect: Option<String>,
+    /// Returns the `Display` form of a plaintext answer of the solver's type.
+    display: fn(&str) -> Option<String>,
 }
 
 impl<'a> Solver<'a> {
-    pub fn new(answer: &'a str, solver: fn() -> String) -> Solver<'a> {
-        Solver {
-            answer: answer,
-            solver: SolverFn::FnOnly(solver),
-        }
+    pub fn new<T: AnswerType>(answer: &'a str, solver: fn() -> T) -> Solver<'a> {
+        Solver::erase(answer, None, Params::new(), move |_, _| Ok(solver()))
+    }
+
+    pub fn new_with_file<T: AnswerType>(
+        answer: &'a str,
+        file_name: &'a str,
+        solver: fn(File) -> io::Result<T>,
+    ) -> Solver<'a> {
+        Solver::erase(answer, Some(file_name), Params::new(), move |file, _| {
+            solver(file.unwrap())
+        })
+    }
+
+    /// Creates a solver whose `answer` is for the default `params`.
+    pub fn new_with_params<T: AnswerType>(
+        answer: &'a str,
+        params: Params,
+        solver: fn(&Params) -> T,
+    ) -> Solver<'a> {
+        Solver::erase(answer, None, params, move |_, params| Ok(solver(params)))
     }
 
-    pub fn new_with_file(
+    /// Creates a solver whose `answer` is for the default `params`.
+    pub fn new_with_file_and_params<T: AnswerType>(
         answer: &'a str,
         file_name: &'a str,
-        solver: fn(File) -> io::Result<String>,
+        params: Params,
+        solver: fn(File, &Params) -> io::Result<T>,
     ) -> Solver<'a> {
+        Solver::erase(answer, Some(file_name), params, move |file, params| {
+            solver(file.unwrap(), params)
+        })
+    }
+
+    fn erase<T, F>(answer: &'a str, file_name: Option<&'a str>, params: Params, f: F) -> Solver<'a>
+    where
+        T: AnswerType,
+        F: Fn(Option<File>, &Params) -> io::Result<T> + 'a,
+    {
         Solver {
             answer: answer,
-            solver: SolverFn::FnWithFile(file_name, solver),
+            file_name: file_name,
+            variants: vec![(DEFAULT_VARIANT, Solver::erase_fn(f))],
+            variant: 0,
+            params: params,
+            input: None,
+            expect: None,
+            display: display_answer::<T>,
+        }
+    }
+
+    fn erase_fn<T, F>(f: F) -> RunFn<'a>
+    where
+        T: AnswerType,
+        F: Fn(Option<File>, &Params) -> io::Result<T> + 'a,
+    {
+        Box::new(move |file, params, answer| {
+            let (time, value) = bench(|| f(file, params));
+            let value = value?;
+            Ok((time, Answer::of(&value)?, answer_matches(answer, &value)))
+        })
+    }
+
+    /// Names the variant given to the constructor, which is run by default.
+    pub fn named(mut self, name: &'a str) -> Solver<'a> {
+        self.variants[0].0 = name;
+        self
+    }
+
+    /// Adds another way to solve the problem, selected by `--variant` and
+    /// checked against the others by `cross_check`. `f` takes the data file
+    /// if the problem has one.
+    pub fn variant<T, F>(mut self, name: &'a str, f: F) -> Solver<'a>
+    where
+        T: AnswerType,
+        F: Fn(Option<File>, &Params) -> io::Result<T> + 'a,
+    {
+        let run = Solver::erase_fn(f);
+        self.variants.push((name, run));
+        self
+    }
+
+    /// Returns the `Display` form of the plaintext answer `answer`, which is
+    /// what a hashed answer is compared with, or `None` if it does not parse
+    /// as the answer type of the solver or the type is not matched exactly.
+    pub fn display_answer(&self, answer: &str) -> Option<String> {
+        (self.display)(answer)
+    }
+
+    pub fn variant_names(&self) -> Vec<&'a str> {
+        self.variants.iter().map(|&(name, _)| name).collect()
+    }
+
+    /// Makes `solve_with` and `bench_with` run the variant `name`.
+    pub fn select_variant(&mut self, name: &str) -> Result<()> {
+        match self.variants.iter().position(|&(n, _)| n == name) {
+            Some(i) => {
+                self.variant = i;
+                Ok(())
+            }
+            None => Err(Error::BadCli(format!(
+                "unknown variant: {} (expected one of {})",
+                name,
+                self.variant_names().join(", ")
+            ))),
+        }
+    }
+
+    pub fn cross_check(&self, assignments: &[&str]) -> Result<()> {
+        self.cross_check_with(&Resources::from_env()?, assignments)
+    }
+
+    /// Runs every variant with the parameters set by `assignments` and fails
+    /// unless they give the same answer. With the default parameters, the
+    /// answer must also be the expected one.
+    pub fn cross_check_with(&self, resources: &Resources, assignments: &[&str]) -> Result<()> {
+        let mut params = self.params.clone();
+        for assignment in assignments {
+            params.set(assignment)?;
+        }
+        let checked = params.is_default();
+
+        let mut reference: Option<(&str, Answer)> = None;
+        for &(name, ref run) in &self.variants {
+            let (_, answer, matches) = run(self.open_file(resources)?, &params, self.answer)?;
+            if checked && !matches {
+                return Err(Error::VariantMismatch(format!(
+                    "variant {} answered {}, which is wrong",
+                    name, answer
+                )));
+            }
+            match reference {
+                Some((ref_name, ref ref_answer)) if *ref_answer != answer => {
+                    return Err(Error::VariantMismatch(format!(
+                        "variant {} answered {}, but {} answered {}",
+                        name, answer, ref_name, ref_answer
+                    )))
+                }
+                Some(_) => {}
+                None => reference = Some((name, answer)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the data file read by the solver, if any.
+    pub fn file_name(&self) -> Option<&'a str> {
+        self.file_name
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    pub fn params_mut(&mut self) -> &mut Params {
+        &mut self.params
+    }
+
+    /// Makes the solver read `path` instead of its data file. The answer is
+    /// not checked then, unless `set_expect` is called.
+    pub fn set_input<P: Into<PathBuf>>(&mut self, path: P) {
+        self.input = Some(path.into());
+    }
+
+    /// Checks the answer against `answer` instead of the registered one,
+    /// whatever the input and parameters.
+    pub fn set_expect<S: Into<String>>(&mut self, answer: S) {
+        self.expect = Some(answer.into());
+    }
+
+    /// Whether there is an expected answer for the input and parameters.
+    fn is_checked(&self) -> bool {
+        self.expect.is_some()
+            || (self.answer != UNKNOWN_ANSWER && self.input.is_none() && self.params.is_default())
+    }
+
+    fn expected(&self, matches: bool) -> Expected {
+        if !self.is_checked() {
+            Expected::Unchecked
+        } else if matches {
+            Expected::Matched
+        } else {
+            Expected::Mismatched
         }
     }
 
-    pub fn run(self) {
+    pub fn run(mut self) {
         let args = env::args().collect::<Vec<_>>();
         let program = &args[0];
 
         let mut opts = Options::new();
         let _ = opts.optflag("", "json", "Output JSON format");
+        let _ = opts.optopt(
+            "",
+            "resource-dir",
+            "Read data files from DIR instead of downloading them",
+            "DIR",
+        );
+        let _ = opts.optflag(
+            "",
+            "allow-unlisted",
+            "Use data files without a digest in the resource manifest",
+        );
+        BenchConfig::define_opts(&mut opts);
+        if !self.params.is_empty() {
+            let _ = opts.optmulti(
+                "",
+                "param",
+                "Set a solver parameter; the answer is checked for the defaults only",
+                "NAME=VALUE",
+            );
+        }
+        if self.variants.len() > 1 {
+            let _ = opts.optopt("", "variant", "Run the solver variant NAME", "NAME");
+        }
+        if self.file_name.is_some() {
+            let _ = opts.optopt(
+                "",
+                "input",
+                "Read PATH, or standard input if -, instead of the data file",
+                "PATH",
+            );
+        }
+        let _ = opts.optopt(
+            "",
+            "expect",
+            "Check the answer against ANSWER instead of the known one",
+            "ANSWER",
+        );
+        let _ = opts.optflag("", "reveal", "Show the answer");
+        let _ = opts.optflag("v", "verbose", "Show the time of each phase of the solver");
+        let _ = opts.optopt(
+            "",
+            "color",
+            "Color the output: auto (default), always or never",
+            "WHEN",
+        );
         let _ = opts.optflag("h", "help", "Display this message");
 
         let matches = match opts.parse(&args[1..]) {
             Ok(m) => m,
             Err(f) => {
                 let _ = writeln!(&mut io::stderr(), "{}: {}", program, f);
-                process::exit(255);
+                process::exit(ErrorKind::BadCli.exit_code());
             }
         };
 
         if matches.opt_present("h") {
             let short = opts.short_usage(&program);
             println!("{}", opts.usage(&short));
+            if !self.params.is_empty() {
+                println!("Parameters:");
+                for (name, default) in self.params.defaults() {
+                    println!("    {} (default {})", name, default);
+                }
+            }
+            if self.variants.len() > 1 {
+                println!("Variants:");
+                for (i, name) in self.variant_names().into_iter().enumerate() {
+                    if i == self.variant {
+                        println!("    {} (default)", name);
+                    } else {
+                        println!("    {}", name);
+                    }
+                }
+            }
             return;
         }
 
-        match self.solve() {
+        if matches.opt_present("json") || matches.opt_present("verbose") {
+            enable_phases();
+        }
+
+        if let Some(when) = matches.opt_str("color") {
+            match when.parse() {
+                Ok(choice) => set_color(choice),
+                Err(err) => {
+                    let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+                    process::exit(ErrorKind::BadCli.exit_code());
+                }
+            }
+        }
+
+        if self.variants.len() > 1 {
+            if let Some(name) = matches.opt_str("variant") {
+                if let Err(err) = self.select_variant(&name) {
+                    let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+                    process::exit(err.kind().exit_code());
+                }
+            }
+        }
+
+        if !self.params.is_empty() {
+            for assignment in matches.opt_strs("param") {
+                if let Err(err) = self.params.set(&assignment) {
+                    let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+                    process::exit(err.kind().exit_code());
+                }
+            }
+        }
+
+        let bench = match BenchConfig::from_matches(&matches) {
+            Ok(bench) => bench,
+            Err(err) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+                process::exit(err.kind().exit_code());
+            }
+        };
+
+        let budgets = match Budgets::load() {
+            Ok(budgets) => budgets,
+            Err(err) => {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+                process::exit(err.kind().exit_code());
+            }
+        };
+
+        if let Some(answer) = matches.opt_str("expect") {
+            self.set_expect(answer);
+        }
+
+        let input = if self.file_name.is_some() {
+            matches.opt_str("input")
+        } else {
+            None
+        };
+        let mut stdin_copy = None;
+        match input.as_ref().map(|s| s.as_str()) {
+            Some("-") => match copy_stdin() {
+                Ok(path) => {
+                    self.set_input(path.clone());
+                    stdin_copy = Some(path);
+                }
+                Err(err) => {
+                    let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+                    process::exit(err.kind().exit_code());
+                }
+            },
+            Some(path) => self.set_input(path),
+            None => {}
+        }
+
+        let resources = match matches.opt_str("resource-dir") {
+            Some(dir) => Ok(Resources::offline(dir)),
+            None => Resources::from_env(),
+        };
+        let resources = resources.map(|mut resources| {
+            if matches.opt_present("allow-unlisted") {
+                resources.set_allow_unlisted(true);
+            }
+            resources
+        });
+
+        let started = schema::timestamp();
+        let result = resources.and_then(|resources| match bench {
+            Some(config) => self.bench_with(&resources, config),
+            None => self.solve_with(&resources),
+        });
+        let id = problem_id(program);
+        if let Some(path) = stdin_copy {
+            let _ = fs::remove_file(path);
+        }
+
+        match result {
             Err(err) => {
                 let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
-                process::exit(255);
+                if matches.opt_present("json") {
+                    let mut output = ErrorOutput::new(err.info());
+                    output.id = id;
+                    output.run = Some(RunInfo::since(started));
+                    let _ = serde_json::to_writer(io::stdout(), &output);
+                    println!();
+                }
+                process::exit(err.kind().exit_code());
             }
-            Ok(result) => {
+            Ok(mut result) => {
+                result.id = id;
+                let (limits, budget) = match id {
+                    Some(id) => (budgets.limits(id), budgets.budget(id)),
+                    None => (budgets.global_limits(), budgets.global),
+                };
+                result.apply_budget(budget);
+                let reveal = matches.opt_present("reveal");
                 if matches.opt_present("json") {
-                    let _ = result.print_json(&mut io::stdout());
+                    let _ = if reveal {
+                        result.print_json(&mut io::stdout())
+                    } else {
+                        result.without_answer().print_json(&mut io::stdout())
+                    };
                 } else {
-                    let _ = result.print_pretty(&program, true);
+                    let _ = if reveal {
+                        result.print_pretty(&program, Some(limits))
+                    } else {
+                        result.without_answer().print_pretty(&program, Some(limits))
+                    };
                 }
-                if !result.is_ok {
-                    process::exit(1);
+                let code = result.exit_code();
+                if code != 0 {
+                    process::exit(code);
                 }
             }
         }
     }
 
-    pub fn solve(&self) -> Result<SolverResult<String>> {
-        let (time, answer) = match self.solver {
-            SolverFn::FnOnly(fun) => bench(move || fun()),
-            SolverFn::FnWithFile(file_name, fun) => {
-                let file = setup_file(file_name)?;
-                let (time, answer) = bench(move || fun(file));
-                (time, answer?)
-            }
-        };
+    pub fn solve(&self) -> Result<SolverResult<Answer>> {
+        self.solve_with(&Resources::from_env()?)
+    }
+
+    pub fn solve_with(&self, resources: &Resources) -> Result<SolverResult<Answer>> {
+        let started = schema::timestamp();
+        let (time, answer, matches, memory, phases) = self.run_once(resources)?;
 
+        let unchecked = !self.is_checked();
         let result = SolverResult {
-            is_ok: answer == self.answer,
+            schema: SCHEMA_VERSION,
+            id: None,
+            expected: Some(self.expected(matches)),
+            run: Some(RunInfo::since(started)),
+            is_ok: unchecked || matches,
+            unchecked: unchecked,
+            slow: false,
             time: time,
             answer: answer,
+            bench: None,
+            memory: memory,
+            phases: phases,
         };
         Ok(result)
     }
-}
 
-fn bench<T, F: FnOnce() -> T>(f: F) -> (u64, T) {
-    let start_time = time::precise_time_ns();
-    let result = f();
-    let end_time = time::precise_time_ns();
-    let nsec = end_time - start_time;
-    (nsec, result)
-}
+    /// Solves the problem `config.warmup + config.runs` times and reports
+    /// statistics of the last `config.runs` runs.
+    pub fn bench_with(
+        &self,
+        resources: &Resources,
+        config: BenchConfig,
+    ) -> Result<SolverResult<Answer>> {
+        let started = schema::timestamp();
+        for _ in 0..config.warmup {
+            let _ = self.run_once(resources)?;
+        }
+
+        let mut samples = vec![];
+        let mut is_ok = true;
+        let mut answer = None;
+        let mut memory = None;
+        let mut phases = vec![];
+        for _ in 0..config.runs {
+            let (time, ans, matches, mem, ph) = self.run_once(resources)?;
+            samples.push(time);
+            is_ok &= matches;
+            answer = Some(ans);
+            memory = mem;
+            phases = ph;
+        }
+
+        let stats = BenchStats::new(config.warmup, &samples);
+        let unchecked = !self.is_checked();
+        let result = SolverResult {
+            schema: SCHEMA_VERSION,
+            id: None,
+            expected: Some(self.expected(is_ok)),
+            run: Some(RunInfo::since(started)),
+            is_ok: unchecked || is_ok,
+            unchecked: unchecked,
+            slow: false,
+            time: stats.median,
+            answer: answer.unwrap(),
+            bench: Some(stats),
+            memory: memory,
+            phases: phases,
+        };
+        Ok(result)
+    }
 
-fn setup_file(file_name: &str) -> Result<File> {
-    let mut path = PathBuf::from("./.cache");
-    path.push(file_name);
-    if !path.is_file() {
-        fs::create_dir_all(&path.parent().unwrap())?;
-        let mut file = File::create(&path)?;
-        let content = download(file_name)?;
-        file.write_all(&content)?;
+    fn run_once(
+        &self,
+        resources: &Resources,
+    ) -> Result<(u64, Answer, bool, Option<MemoryStats>, Vec<Phase>)> {
+        let file = self.open_file(resources)?;
+        let expect = self.expect.as_ref().map_or(self.answer, |s| s.as_str());
+        phase::reset_phases();
+        let tracker = memory::Tracker::start();
+        let (time, answer, matches) = (self.variants[self.variant].1)(file, &self.params, expect)?;
+        Ok((
+            time,
+            answer,
+            matches,
+            tracker.finish(),
+            phase::take_phases(),
+        ))
     }
 
-    let file = File::open(&path)?;
-    Ok(file)
+    fn open_file(&self, resources: &Resources) -> Result<Option<File>> {
+        match (self.file_name, &self.input) {
+            (Some(_), &Some(ref path)) => Ok(Some(File::open(path)?)),
+            (Some(file_name), &None) => Ok(Some(resources.open(file_name)?)),
+            (None, _) => Ok(None),
+        }
+    }
 }
 
-const BASE_URL: &'static str = "http://projecteuler.net/project/resources/";
-fn download(file_name: &str) -> Result<Vec<u8>> {
-    let url = format!("{}{}", BASE_URL, file_name);
+/// Copies the standard input to a temporary file, which is read like a data
+/// file as many times as the solver is run. The file gets an unpredictable
+/// name and is never opened if it already exists; the caller removes it.
+fn copy_stdin() -> Result<PathBuf> {
+    let (path, mut file) = create_temp_file("euler-stdin")?;
+    if let Err(err) = io::copy(&mut io::stdin(), &mut file) {
+        let _ = fs::remove_file(&path);
+        return Err(err.into());
+    }
+    Ok(path)
+}
 
-    let mut retry = 0;
+/// Creates a new file named `prefix` plus a random suffix in the temporary
+/// directory.
+fn create_temp_file(prefix: &str) -> Result<(PathBuf, File)> {
+    let mut attempts = 0;
     loop {
-        let mut resp = reqwest::get(&url)?;
-        let mut body = vec![];
-        let _ = resp.read_to_end(&mut body)?;
-
-        if !resp.status().is_success() {
-            let err = InvalidHttpStatusError {
-                status: resp.status(),
-                body: String::from_utf8_lossy(&body).into(),
-            };
-            let program = env::args().next().unwrap();
-            let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
-            retry += 1;
-            if retry >= 3 {
-                return Err(err.into());
+        let name = format!("{}-{}", prefix, new_salt(prefix));
+        let path = env::temp_dir().join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(ref err) if err.kind() == io::ErrorKind::AlreadyExists && attempts < 16 => {
+                attempts += 1;
             }
+            Err(err) => return Err(err.into()),
         }
+    }
+}
 
-        return Ok(body);
+/// Returns the id of the problem solved by the binary `program`, named
+/// `pNNN`.
+fn problem_id(program: &str) -> Option<u32> {
+    let name = Path::new(program).file_stem()?.to_str()?;
+    if name.len() != 4 || !name.starts_with('p') {
+        return None;
     }
+    name[1..].parse().ok()
 }
 
+/// Registers a problem solver as `solver()` and runs it from `main()`.
+///
+/// Takes the expected answer, `"?"` if it is not known yet, the name of the
+/// data file if the solver reads one, and the solver. Parameters follow as
+/// `name: Type = default`, or `name: Type = default; min value` to reject
+/// smaller values; the solver then receives them as `&Params`. Metadata may precede the answer as
+/// `title: "..", difficulty: N, tags: [..]`, with the difficulty rating in
+/// percent and the tags optional, and is registered as `META`.
+///
+/// In place of the solver, `variants { name: solver, .. }` registers several
+/// solvers of the same kind, the first of which is run by default. They are
+/// tested to give the same answer, with the parameters set as in
+/// `variants["name=value", ..] { .. }` if given.
 #[macro_export]
 macro_rules! problem {
+    (@solver [$($check:expr),*] $solver:expr) => {
+        /// Returns the solver registered for this problem.
+        pub fn solver() -> $crate::Solver<'static> {
+            $solver
+        }
+
+        #[allow(dead_code)]
+        fn main() {
+            solver().run();
+        }
+
+        #[test]
+        fn test_solve() {
+            assert!(solver().solve().unwrap().is_ok);
+        }
+
+        #[test]
+        fn test_variants() {
+            solver().cross_check(&[$($check),*]).unwrap();
+        }
+    };
+    (@variants [$($check:expr),*] $answer:expr,
+     variants { $first:ident: $fsolver:expr $(, $name:ident: $solver:expr)* $(,)* }) => {
+        problem!(@solver [$($check),*]
+            $crate::Solver::new($answer, $fsolver)
+                .named(stringify!($first))
+                $(.variant(stringify!($name), move |_, _| Ok($solver())))*
+        );
+    };
+    (@variants [$($check:expr),*] $answer:expr,
+     variants { $first:ident: $fsolver:expr $(, $name:ident: $solver:expr)* $(,)* },
+     $($pname:ident: $ty:ty = $default:expr $(; min $pmin:expr)*),+) => {
+        problem!(@solver [$($check),*]
+            $crate::Solver::new_with_params(
+                $answer,
+                $crate::Params::new()
+                    $(.define::<$ty>(stringify!($pname), $default)$(.at_least::<$ty>($pmin))*)+,
+                $fsolver,
+            )
+            .named(stringify!($first))
+            $(.variant(stringify!($name), move |_, params| Ok($solver(params))))*
+        );
+    };
+    (@variants [$($check:expr),*] $answer:expr, $file:expr,
+     variants { $first:ident: $fsolver:expr $(, $name:ident: $solver:expr)* $(,)* }) => {
+        problem!(@solver [$($check),*]
+            $crate::Solver::new_with_file($answer, $file, $fsolver)
+                .named(stringify!($first))
+                $(.variant(stringify!($name), move |file, _| $solver(file.unwrap())))*
+        );
+    };
+    (@variants [$($check:expr),*] $answer:expr, $file:expr,
+     variants { $first:ident: $fsolver:expr $(, $name:ident: $solver:expr)* $(,)* },
+     $($pname:ident: $ty:ty = $default:expr $(; min $pmin:expr)*),+) => {
+        problem!(@solver [$($check),*]
+            $crate::Solver::new_with_file_and_params(
+                $answer,
+                $file,
+                $crate::Params::new()
+                    $(.define::<$ty>(stringify!($pname), $default)$(.at_least::<$ty>($pmin))*)+,
+                $fsolver,
+            )
+            .named(stringify!($first))
+            $(.variant(stringify!($name), move |file, params| $solver(file.unwrap(), params)))*
+        );
+    };
+    (@meta $title:expr, $difficulty:expr, [$($tag:expr),*], $($rest:tt)+) => {
+        /// Metadata of this problem.
+        pub const META: $crate::Meta = $crate::Meta {
+            title: $title,
+            difficulty: $difficulty,
+            tags: &[$($tag),*],
+        };
+
+        problem!($($rest)+);
+    };
+    (title: $title:expr, difficulty: $difficulty:expr, tags: [$($tag:expr),*], $($rest:tt)+) => {
+        problem!(@meta $title, Some($difficulty), [$($tag),*], $($rest)+);
+    };
+    (title: $title:expr, difficulty: $difficulty:expr, $($rest:tt)+) => {
+        problem!(@meta $title, Some($difficulty), [], $($rest)+);
+    };
+    (title: $title:expr, tags: [$($tag:expr),*], $($rest:tt)+) => {
+        problem!(@meta $title, None, [$($tag),*], $($rest)+);
+    };
+    (title: $title:expr, $($rest:tt)+) => {
+        problem!(@meta $title, None, [], $($rest)+);
+    };
+    ($answer:expr, variants { $($v:tt)* } $($rest:tt)*) => {
+        problem!(@variants [] $answer, variants { $($v)* } $($rest)*);
+    };
+    ($answer:expr, variants[$($check:expr),*] { $($v:tt)* } $($rest:tt)*) => {
+        problem!(@variants [$($check),*] $answer, variants { $($v)* } $($rest)*);
+    };
+    ($answer:expr, $file:expr, variants { $($v:tt)* } $($rest:tt)*) => {
+        problem!(@variants [] $answer, $file, variants { $($v)* } $($rest)*);
+    };
+    ($answer:expr, $file:expr, variants[$($check:expr),*] { $($v:tt)* } $($rest:tt)*) => {
+        problem!(@variants [$($check),*] $answer, $file, variants { $($v)* } $($rest)*);
+    };
+    ($answer:expr, $solver:expr, $($name:ident: $ty:ty = $default:expr $(; min $min:expr)*),+) => {
+        /// Returns the solver registered for this problem.
+        pub fn solver() -> $crate::Solver<'static> {
+            $crate::Solver::new_with_params(
+                $answer,
+                $crate::Params::new()
+                    $(.define::<$ty>(stringify!($name), $default)$(.at_least::<$ty>($min))*)+,
+                $solver,
+            )
+        }
+
+        #[allow(dead_code)]
+        fn main() {
+            solver().run();
+        }
+
+        #[test]
+        fn test_solve() {
+            assert!(solver().solve().unwrap().is_ok);
+        }
+    };
+    ($answer:expr, $file:expr, $solver:expr,
+     $($name:ident: $ty:ty = $default:expr $(; min $min:expr)*),+) => {
+        /// Returns the solver registered for this problem.
+        pub fn solver() -> $crate::Solver<'static> {
+            $crate::Solver::new_with_file_and_params(
+                $answer,
+                $file,
+                $crate::Params::new()
+                    $(.define::<$ty>(stringify!($name), $default)$(.at_least::<$ty>($min))*)+,
+                $solver,
+            )
+        }
+
+        #[allow(dead_code)]
+        fn main() {
+            solver().run();
+        }
+
+        #[test]
+        fn test_solve() {
+            assert!(solver().solve().unwrap().is_ok);
+        }
+    };
     ($answer:expr, $solver:expr) => {
+        /// Returns the solver registered for this problem.
+        pub fn solver() -> $crate::Solver<'static> {
+            $crate::Solver::new($answer, $solver)
+        }
+
+        #[allow(dead_code)]
         fn main() {
-            $crate::Solver::new($answer, $solver).run();
+            solver().run();
         }
 
         #[test]
         fn test_solve() {
-            assert!($crate::Solver::new($answer, $solver).solve().unwrap().is_ok);
+            assert!(solver().solve().unwrap().is_ok);
         }
     };
     ($answer:expr, $file:expr, $solver:expr) => {
+        /// Returns the solver registered for this problem.
+        pub fn solver() -> $crate::Solver<'static> {
+            $crate::Solver::new_with_file($answer, $file, $solver)
+        }
+
+        #[allow(dead_code)]
         fn main() {
-            $crate::Solver::new_with_file($answer, $file, $solver).run();
+            solver().run();
         }
 
         #[test]
         fn test_solve() {
-            assert!(
-                $crate::Solver::new_with_file($answer, $file, $solver)
-                    .solve()
-                    .unwrap()
-                    .is_ok
-            );
+            assert!(solver().solve().unwrap().is_ok);
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Budgets, ErrorKind, Resources, Solver, Status};
+    use std::env;
+
+    #[test]
+    fn slow_exit_code() {
+        let resources = Resources::offline(env::temp_dir());
+        let budgets = Budgets {
+            global: Some(5),
+            ..Budgets::default()
+        };
+
+        let mut result = Solver::new("2", || 2u32).solve_with(&resources).unwrap();
+        result.time = 10;
+        assert_eq!(0, result.exit_code());
+        result.apply_budget(budgets.budget(1));
+        assert_eq!(Status::Slow, result.status());
+        assert_eq!(11, result.exit_code());
+        assert_eq!(ErrorKind::Slow.exit_code(), result.exit_code());
+
+        result.time = 5;
+        result.apply_budget(budgets.budget(1));
+        assert_eq!(0, result.exit_code());
+
+        // A wrong answer fails as such however long it took.
+        let mut result = Solver::new("3", || 2u32).solve_with(&resources).unwrap();
+        result.time = 10;
+        result.apply_budget(budgets.budget(1));
+        assert_eq!(Status::Ng, result.status());
+        assert_eq!(ErrorKind::WrongAnswer.exit_code(), result.exit_code());
+    }
+}
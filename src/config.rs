@@ -0,0 +1,316 @@
+//! Settings of the harness: time budgets, read from `euler.conf` and the
+//! environment, and the color policy of pretty output.
+//!
+//! `euler.conf` holds `key = value` lines, and `#` starts a comment:
+//!
+//! ```text
+//! # Show times from 2s in the warning color.
+//! warn = 2s
+//! # Report problems that take longer than 10s as SLOW,
+//! budget = 10s
+//! # except for p014, which may take 30s.
+//! budget.p014 = 30s
+//! ```
+//!
+//! `EULER_WARN`, `EULER_BUDGET` and `EULER_BUDGET_P014` override the file,
+//! which is read from `EULER_CONFIG` if it is set.
+
+use libc;
+use select::parse_duration;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use {Error, Result};
+
+pub const CONFIG_FILE: &'static str = "euler.conf";
+const CONFIG_ENV: &'static str = "EULER_CONFIG";
+const WARN_ENV: &'static str = "EULER_WARN";
+const BUDGET_ENV: &'static str = "EULER_BUDGET";
+const NO_COLOR_ENV: &'static str = "NO_COLOR";
+
+const NSEC_PER_SEC: u64 = 1_000_000_000;
+const NSEC_WARN_LIMIT: u64 = 1 * NSEC_PER_SEC;
+const NSEC_NG_LIMIT: u64 = 10 * NSEC_PER_SEC;
+
+/// Times from which a run is shown in the warning and failure colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeLimits {
+    pub warn: u64,
+    /// Runs over this are shown in the failure color: the budget of the
+    /// problem, or 10s without one.
+    pub ng: u64,
+}
+
+impl Default for TimeLimits {
+    fn default() -> TimeLimits {
+        TimeLimits {
+            warn: NSEC_WARN_LIMIT,
+            ng: NSEC_NG_LIMIT,
+        }
+    }
+}
+
+/// How long problems may take, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Budgets {
+    /// Times from here are shown in the warning color.
+    pub warn: u64,
+    /// Budget of the problems without their own.
+    pub global: Option<u64>,
+    pub problems: BTreeMap<u32, u64>,
+}
+
+impl Default for Budgets {
+    fn default() -> Budgets {
+        Budgets {
+            warn: NSEC_WARN_LIMIT,
+            global: None,
+            problems: BTreeMap::new(),
+        }
+    }
+}
+
+impl Budgets {
+    /// Reads `EULER_CONFIG`, or `euler.conf` if it exists, and applies the
+    /// environment variables on top of it.
+    pub fn load() -> Result<Budgets> {
+        let mut budgets = match env::var_os(CONFIG_ENV) {
+            Some(path) => Budgets::read(Path::new(&path))?,
+            None => match Budgets::read(Path::new(CONFIG_FILE)) {
+                Err(Error::Io(ref e)) if e.kind() == io::ErrorKind::NotFound => Budgets::default(),
+                result => result?,
+            },
+        };
+        budgets.apply_env(env::vars())?;
+        Ok(budgets)
+    }
+
+    fn read(path: &Path) -> Result<Budgets> {
+        let mut content = String::new();
+        let _ = File::open(path)?.read_to_string(&mut content)?;
+        match content.parse() {
+            Err(Error::Parse(msg)) => Err(Error::Parse(format!("{}: {}", path.display(), msg))),
+            result => result,
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> result::Result<(), String> {
+        let nsec = parse_duration(value).map_err(|_| format!("invalid duration: {}", value))?;
+        if key == "warn" {
+            self.warn = nsec;
+        } else if key == "budget" {
+            self.global = Some(nsec);
+        } else if key.starts_with("budget.p") {
+            let id = match parse_id(&key["budget.p".len()..]) {
+                Some(id) => id,
+                None => return Err(format!("invalid problem: {}", key)),
+            };
+            let _ = self.problems.insert(id, nsec);
+        } else {
+            return Err(format!("unknown setting: {}", key));
+        }
+        Ok(())
+    }
+
+    /// Applies `EULER_WARN`, `EULER_BUDGET` and `EULER_BUDGET_PNNN`. Other
+    /// variables starting with `EULER_BUDGET_` are errors.
+    fn apply_env<I: Iterator<Item = (String, String)>>(&mut self, vars: I) -> Result<()> {
+        let prefix = format!("{}_", BUDGET_ENV);
+        for (name, value) in vars {
+            let key = if name == WARN_ENV {
+                "warn".to_string()
+            } else if name == BUDGET_ENV {
+                "budget".to_string()
+            } else if name.starts_with(&prefix) {
+                let problem = &name[prefix.len()..];
+                let id = if problem.starts_with('P') {
+                    parse_id(&problem[1..])
+                } else {
+                    None
+                };
+                match id {
+                    Some(id) => format!("budget.p{}", id),
+                    None => {
+                        return Err(Error::BadCli(format!(
+                            "{}: unknown variable, expected {}P followed by a problem id",
+                            name, prefix
+                        )))
+                    }
+                }
+            } else {
+                continue;
+            };
+            self.set(&key, &value)
+                .map_err(|msg| Error::BadCli(format!("{}: {}", name, msg)))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the budget of problem `id`, if it has one.
+    pub fn budget(&self, id: u32) -> Option<u64> {
+        self.problems.get(&id).cloned().or(self.global)
+    }
+
+    /// Returns the time limits used to color the time of problem `id`.
+    pub fn limits(&self, id: u32) -> TimeLimits {
+        TimeLimits {
+            warn: self.warn,
+            ng: self.budget(id).unwrap_or(NSEC_NG_LIMIT),
+        }
+    }
+
+    /// Returns the time limits of problems without their own budget, used
+    /// for times not of a single problem.
+    pub fn global_limits(&self) -> TimeLimits {
+        TimeLimits {
+            warn: self.warn,
+            ng: self.global.unwrap_or(NSEC_NG_LIMIT),
+        }
+    }
+}
+
+/// Parses the id of a problem, which must be all digits as in `014`.
+fn parse_id(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+impl FromStr for Budgets {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Budgets> {
+        let mut budgets = Budgets::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut kv = line.splitn(2, '=');
+            let (key, value) = match (kv.next(), kv.next()) {
+                (Some(key), Some(value)) => (key.trim(), value.trim()),
+                _ => {
+                    return Err(Error::Parse(format!(
+                        "line {}: expected KEY = VALUE",
+                        i + 1
+                    )))
+                }
+            };
+            budgets
+                .set(key, value)
+                .map_err(|msg| Error::Parse(format!("line {}: {}", i + 1, msg)))?;
+        }
+        Ok(budgets)
+    }
+}
+
+/// When pretty output is colored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// If standard output is a terminal and `NO_COLOR` is not set.
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ColorChoice> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(Error::BadCli(format!("unknown color choice: {}", s))),
+        }
+    }
+}
+
+static COLOR: AtomicUsize = AtomicUsize::new(ColorChoice::Auto as usize);
+
+/// Sets the color policy of pretty output for the whole process.
+pub fn set_color(choice: ColorChoice) {
+    COLOR.store(choice as usize, Ordering::Relaxed);
+}
+
+/// Returns true if pretty output should be colored.
+pub fn use_color() -> bool {
+    match COLOR.load(Ordering::Relaxed) {
+        c if c == ColorChoice::Always as usize => true,
+        c if c == ColorChoice::Never as usize => false,
+        _ => {
+            let no_color = env::var_os(NO_COLOR_ENV).map_or(false, |v| !v.is_empty());
+            !no_color && unsafe { libc::isatty(libc::STDOUT_FILENO) } != 0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Budgets, ColorChoice, TimeLimits};
+
+    const SEC: u64 = 1_000_000_000;
+
+    #[test]
+    fn parse() {
+        let budgets = "# budgets\nwarn = 2s\n\nbudget = 10s # global\nbudget.p014 = 30s\n"
+            .parse::<Budgets>()
+            .unwrap();
+        assert_eq!(2 * SEC, budgets.warn);
+        assert_eq!(Some(30 * SEC), budgets.budget(14));
+        assert_eq!(Some(10 * SEC), budgets.budget(1));
+        assert_eq!(
+            TimeLimits {
+                warn: 2 * SEC,
+                ng: 30 * SEC,
+            },
+            budgets.limits(14)
+        );
+
+        assert!("budget".parse::<Budgets>().is_err());
+        assert!("budget = soon".parse::<Budgets>().is_err());
+        assert!("budget.14 = 1s".parse::<Budgets>().is_err());
+        assert!("budget.p+14 = 1s".parse::<Budgets>().is_err());
+        assert!("timeout = 1s".parse::<Budgets>().is_err());
+    }
+
+    #[test]
+    fn env() {
+        let mut budgets = "budget = 10s".parse::<Budgets>().unwrap();
+        let vars = vec![
+            ("EULER_BUDGET".to_string(), "5s".to_string()),
+            ("EULER_BUDGET_P096".to_string(), "500ms".to_string()),
+            ("HOME".to_string(), "/".to_string()),
+        ];
+        budgets.apply_env(vars.into_iter()).unwrap();
+        assert_eq!(Some(5 * SEC), budgets.budget(1));
+        assert_eq!(Some(SEC / 2), budgets.budget(96));
+        assert_eq!(None, Budgets::default().budget(1));
+        assert_eq!(TimeLimits::default(), Budgets::default().limits(1));
+        assert_eq!(10 * SEC, Budgets::default().limits(1).ng);
+
+        for name in &[
+            "EULER_BUDGET_PFOO",
+            "EULER_BUDGET_P",
+            "EULER_BUDGET_14",
+            "EULER_BUDGET_X",
+        ] {
+            let vars = vec![(name.to_string(), "1s".to_string())];
+            let err = Budgets::default().apply_env(vars.into_iter()).unwrap_err();
+            assert!(err.to_string().contains(name), "{}", err);
+        }
+    }
+
+    #[test]
+    fn color() {
+        assert_eq!(ColorChoice::Never, "never".parse().unwrap());
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+}
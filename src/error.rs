@@ -0,0 +1,249 @@
+//! Errors of the harness, with the exit codes and JSON they are reported as.
+
+use resource::FetchError;
+use schema::{self, RunInfo, SCHEMA_VERSION};
+use serde_json;
+use std::io;
+
+/// Why solving a problem failed, or why the harness could not run at all.
+#[derive(Fail, Debug)]
+pub enum Error {
+    /// A data file could not be fetched.
+    #[fail(display = "{}", _0)]
+    Download(#[cause] FetchError),
+    /// A data file does not match its digest in the resource manifest.
+    #[fail(
+        display = "{}: checksum mismatch, expected {}, found {}",
+        file_name, expected, actual
+    )]
+    ChecksumMismatch {
+        file_name: String,
+        expected: String,
+        actual: String,
+    },
+    /// A data file has no digest in the resource manifest.
+    #[fail(
+        display = "{}: no digest in the resource manifest (--allow-unlisted or \
+                   EULER_ALLOW_UNLISTED=1 uses it anyway)",
+        _0
+    )]
+    Unlisted(String),
+    /// Reading or writing a local file failed.
+    #[fail(display = "{}", _0)]
+    Io(#[cause] io::Error),
+    /// A data file or saved results could not be parsed.
+    #[fail(display = "parse error: {}", _0)]
+    Parse(String),
+    /// The solver panicked or crashed.
+    #[fail(display = "panicked: {}", _0)]
+    Panic(String),
+    /// The solver gave an answer other than the expected one.
+    #[fail(display = "wrong answer")]
+    WrongAnswer,
+    /// Variants of a solver disagree on the answer.
+    #[fail(display = "{}", _0)]
+    VariantMismatch(String),
+    /// The solver was killed after running too long.
+    #[fail(display = "timed out")]
+    Timeout,
+    /// The solver ran out of its memory limit.
+    #[fail(display = "out of memory")]
+    OutOfMemory,
+    /// Problems got slower or changed their answers since the baseline.
+    #[fail(display = "{} regression(s) against the baseline", _0)]
+    Regression(usize),
+    /// The command line is invalid.
+    #[fail(display = "{}", _0)]
+    BadCli(String),
+}
+
+impl Error {
+    pub fn kind(&self) -> ErrorKind {
+        match *self {
+            Error::Download(_) => ErrorKind::Download,
+            Error::ChecksumMismatch { .. } | Error::Unlisted(_) => ErrorKind::ChecksumMismatch,
+            Error::Io(_) => ErrorKind::Io,
+            Error::Parse(_) => ErrorKind::Parse,
+            Error::Panic(_) => ErrorKind::Panic,
+            Error::WrongAnswer | Error::VariantMismatch(_) => ErrorKind::WrongAnswer,
+            Error::Timeout => ErrorKind::Timeout,
+            Error::OutOfMemory => ErrorKind::OutOfMemory,
+            Error::Regression(_) => ErrorKind::Regression,
+            Error::BadCli(_) => ErrorKind::BadCli,
+        }
+    }
+
+    /// Returns the information about the error that is kept in results.
+    pub fn info(&self) -> ErrorInfo {
+        ErrorInfo {
+            kind: self.kind(),
+            message: self.to_string(),
+            location: None,
+        }
+    }
+}
+
+impl From<FetchError> for Error {
+    fn from(e: FetchError) -> Error {
+        Error::Download(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    /// Solvers report malformed data files as `InvalidData`.
+    fn from(e: io::Error) -> Error {
+        if e.kind() == io::ErrorKind::InvalidData {
+            Error::Parse(e.to_string())
+        } else {
+            Error::Io(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        if e.is_io() {
+            Error::Io(e.into())
+        } else {
+            Error::Parse(e.to_string())
+        }
+    }
+}
+
+/// Class of an `Error`, which determines the exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    WrongAnswer,
+    BadCli,
+    Timeout,
+    OutOfMemory,
+    Panic,
+    Download,
+    ChecksumMismatch,
+    Io,
+    Parse,
+    Regression,
+    Slow,
+}
+
+impl ErrorKind {
+    /// Returns the exit code of a process failing with this kind of error.
+    ///
+    /// | Code | Kind               |
+    /// |------|--------------------|
+    /// |    0 | (success)          |
+    /// |    1 | wrong answer       |
+    /// |    2 | bad command line   |
+    /// |    3 | timeout            |
+    /// |    4 | out of memory      |
+    /// |    5 | panic              |
+    /// |    6 | download failure   |
+    /// |    7 | checksum mismatch  |
+    /// |    8 | I/O error          |
+    /// |    9 | parse error        |
+    /// |   10 | regression         |
+    /// |   11 | over time budget   |
+    pub fn exit_code(&self) -> i32 {
+        match *self {
+            ErrorKind::WrongAnswer => 1,
+            ErrorKind::BadCli => 2,
+            ErrorKind::Timeout => 3,
+            ErrorKind::OutOfMemory => 4,
+            ErrorKind::Panic => 5,
+            ErrorKind::Download => 6,
+            ErrorKind::ChecksumMismatch => 7,
+            ErrorKind::Io => 8,
+            ErrorKind::Parse => 9,
+            ErrorKind::Regression => 10,
+            ErrorKind::Slow => 11,
+        }
+    }
+
+    /// Returns the label shown in pretty output for a problem that failed
+    /// with this kind of error.
+    pub fn label(&self) -> &'static str {
+        match *self {
+            ErrorKind::WrongAnswer => "NG",
+            ErrorKind::BadCli => "BADCLI",
+            ErrorKind::Timeout => "TIMEOUT",
+            ErrorKind::OutOfMemory => "OOM",
+            ErrorKind::Panic => "PANIC",
+            ErrorKind::Download => "DOWNLOAD",
+            ErrorKind::ChecksumMismatch => "CHECKSUM",
+            ErrorKind::Io => "IO",
+            ErrorKind::Parse => "PARSE",
+            ErrorKind::Regression => "REGRESSION",
+            ErrorKind::Slow => "SLOW",
+        }
+    }
+}
+
+/// The `error` field of JSON output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorInfo {
+    pub kind: ErrorKind,
+    pub message: String,
+    /// Where a solver panicked, as `file:line:column`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl ErrorInfo {
+    pub fn new<S: Into<String>>(kind: ErrorKind, message: S) -> ErrorInfo {
+        ErrorInfo {
+            kind: kind,
+            message: message.into(),
+            location: None,
+        }
+    }
+}
+
+/// JSON printed instead of a result when solving fails.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorOutput {
+    /// Version of the JSON shape, as in `SolverResult`.
+    #[serde(default = "schema::legacy_schema")]
+    pub schema: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub error: ErrorInfo,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<RunInfo>,
+}
+
+impl ErrorOutput {
+    pub fn new(error: ErrorInfo) -> ErrorOutput {
+        ErrorOutput {
+            schema: SCHEMA_VERSION,
+            id: None,
+            error: error,
+            run: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind, ErrorOutput};
+    use serde_json;
+    use std::io;
+
+    #[test]
+    fn io_kinds() {
+        let e = Error::from(io::Error::new(io::ErrorKind::InvalidData, "bad line"));
+        assert_eq!(ErrorKind::Parse, e.kind());
+        let e = Error::from(io::Error::new(io::ErrorKind::NotFound, "no file"));
+        assert_eq!(ErrorKind::Io, e.kind());
+    }
+
+    #[test]
+    fn json() {
+        let output = ErrorOutput::new(Error::Panic("index out of bounds".to_string()).info());
+        assert_eq!(
+            r#"{"schema":2,"error":{"kind":"panic","message":"panicked: index out of bounds"}}"#,
+            serde_json::to_string(&output).unwrap()
+        );
+        assert_eq!(5, output.error.kind.exit_code());
+    }
+}
@@ -0,0 +1,466 @@
+//! Seeded random inputs in the formats of the data files, for stress tests
+//! and benchmarks of the file-based solvers at other sizes.
+//!
+//! The generator is a SplitMix64 of its own, so a seed gives the same input
+//! on every platform and with every version of the dependencies.
+
+use std::fmt::Write;
+
+/// A generator: the data file it imitates, its default size and the function
+/// writing an input of a given size.
+type Generator = (&'static str, usize, fn(&mut Rng, usize) -> String);
+
+/// Sizes are counted in the unit of each format: names, hands, rows, grids,
+/// vertices and so on. The defaults are about the size of the real files.
+const GENERATORS: &'static [Generator] = &[
+    ("p022_names.txt", 5000, words),
+    ("p042_words.txt", 2000, words),
+    ("p054_poker.txt", 1000, poker_hands),
+    ("p059_cipher.txt", 250, cipher),
+    ("p067_triangle.txt", 100, triangle),
+    ("p079_keylog.txt", 50, keylog),
+    ("p081_matrix.txt", 80, matrix),
+    ("p082_matrix.txt", 80, matrix),
+    ("p083_matrix.txt", 80, matrix),
+    ("p089_roman.txt", 1000, roman_numerals),
+    ("p096_sudoku.txt", 50, sudoku_grids),
+    ("p098_words.txt", 2000, words),
+    ("p099_base_exp.txt", 1000, base_exps),
+    ("p102_triangles.txt", 1000, triangles),
+    ("p105_sets.txt", 100, sets),
+    ("p107_network.txt", 40, network),
+];
+
+/// Returns the data files that inputs can be generated for.
+pub fn generated_files() -> Vec<&'static str> {
+    GENERATORS.iter().map(|g| g.0).collect()
+}
+
+/// Returns an input in the format of the data file `file_name`, of `size`
+/// items or of about the size of the real file, or `None` if there is no
+/// generator for the file.
+pub fn generate(file_name: &str, size: Option<usize>, seed: u64) -> Option<String> {
+    let &(_, default, generator) = GENERATORS.iter().find(|g| g.0 == file_name)?;
+    Some(generator(&mut Rng::new(seed), size.unwrap_or(default)))
+}
+
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `lo..hi`.
+    fn range(&mut self, lo: u64, hi: u64) -> u64 {
+        lo + self.next_u64() % (hi - lo)
+    }
+
+    fn ratio(&mut self, num: u64, den: u64) -> bool {
+        self.range(0, den) < num
+    }
+
+    fn shuffle<T>(&mut self, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            let j = self.range(0, i as u64 + 1) as usize;
+            v.swap(i, j);
+        }
+    }
+}
+
+/// `"WORD","WORD",...` on one line.
+fn words(rng: &mut Rng, count: usize) -> String {
+    let words = (0..count)
+        .map(|_| {
+            let len = rng.range(2, 11);
+            let word = (0..len)
+                .map(|_| (b'A' + rng.range(0, 26) as u8) as char)
+                .collect::<String>();
+            format!("\"{}\"", word)
+        })
+        .collect::<Vec<_>>();
+    words.join(",")
+}
+
+/// Ten distinct cards such as `8C TS KC 9H 4S 7D 2S 5D 3S AC` per line.
+fn poker_hands(rng: &mut Rng, count: usize) -> String {
+    let mut deck = vec![];
+    for rank in "23456789TJQKA".chars() {
+        for suit in "CDHS".chars() {
+            deck.push(format!("{}{}", rank, suit));
+        }
+    }
+    let mut s = String::new();
+    for _ in 0..count {
+        rng.shuffle(&mut deck);
+        let _ = writeln!(s, "{}", deck[..10].join(" "));
+    }
+    s
+}
+
+const PLAIN_WORDS: &'static [&'static str] = &[
+    "the", "of", "and", "to", "in", "is", "that", "it", "was", "for", "on", "are", "with", "as",
+    "his", "they", "be", "at", "one", "have", "this", "from", "or", "had", "by", "word", "but",
+    "what", "some", "we", "can", "out", "other", "were", "all", "there", "when", "up", "use",
+    "your", "how", "said", "an", "each", "she", "which", "do", "their", "time", "if", "will",
+    "way", "about", "many", "then", "them", "write", "would", "like", "so", "these",
+];
+
+/// English words encrypted by XOR with a key of three lower case letters, as
+/// comma separated character codes.
+fn cipher(rng: &mut Rng, words: usize) -> String {
+    let key = (0..3)
+        .map(|_| b'a' + rng.range(0, 26) as u8)
+        .collect::<Vec<_>>();
+    let text = (0..words)
+        .map(|_| PLAIN_WORDS[rng.range(0, PLAIN_WORDS.len() as u64) as usize])
+        .collect::<Vec<_>>()
+        .join(" ");
+    let codes = text
+        .bytes()
+        .zip(key.iter().cycle())
+        .map(|(c, k)| (c ^ k).to_string())
+        .collect::<Vec<_>>();
+    codes.join(",")
+}
+
+/// Rows of two-digit numbers, the `n`th row having `n` of them.
+fn triangle(rng: &mut Rng, rows: usize) -> String {
+    let mut s = String::new();
+    for i in 0..rows {
+        let row = (0..i + 1)
+            .map(|_| format!("{:02}", rng.range(0, 100)))
+            .collect::<Vec<_>>();
+        let _ = writeln!(s, "{}", row.join(" "));
+    }
+    s
+}
+
+/// Three digits of a passcode of distinct digits, in order, per line.
+fn keylog(rng: &mut Rng, count: usize) -> String {
+    let mut digits = (b'0'..b'9' + 1).map(|b| b as char).collect::<Vec<_>>();
+    rng.shuffle(&mut digits);
+    let passcode = &digits[..8];
+    let mut s = String::new();
+    for _ in 0..count {
+        let mut positions = (0..passcode.len()).collect::<Vec<_>>();
+        rng.shuffle(&mut positions);
+        let mut positions = positions[..3].to_vec();
+        positions.sort();
+        let attempt = positions.iter().map(|&i| passcode[i]).collect::<String>();
+        let _ = writeln!(s, "{}", attempt);
+    }
+    s
+}
+
+/// An `n`x`n` matrix of comma separated numbers.
+fn matrix(rng: &mut Rng, n: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..n {
+        let row = (0..n)
+            .map(|_| rng.range(1, 10000).to_string())
+            .collect::<Vec<_>>();
+        let _ = writeln!(s, "{}", row.join(","));
+    }
+    s
+}
+
+/// Valid Roman numerals, some of them in a longer form than needed.
+fn roman_numerals(rng: &mut Rng, count: usize) -> String {
+    const PLACES: [(char, char, char); 3] = [('C', 'D', 'M'), ('X', 'L', 'C'), ('I', 'V', 'X')];
+    let mut s = String::new();
+    for _ in 0..count {
+        let n = rng.range(1, 5000);
+        let mut numeral = "M".repeat((n / 1000) as usize);
+        for (i, &(one, five, ten)) in PLACES.iter().enumerate() {
+            let digit = n / 10u64.pow(2 - i as u32) % 10;
+            let long = rng.ratio(1, 2);
+            let ones = |k: u64| one.to_string().repeat(k as usize);
+            match digit {
+                4 if !long => numeral.push_str(&format!("{}{}", one, five)),
+                9 if !long => numeral.push_str(&format!("{}{}", one, ten)),
+                0..=4 => numeral.push_str(&ones(digit)),
+                _ => numeral.push_str(&format!("{}{}", five, ones(digit - 5))),
+            }
+        }
+        let _ = writeln!(s, "{}", numeral);
+    }
+    s
+}
+
+/// Grids named `Grid NN`, each a puzzle with a single solution: nine lines of
+/// nine digits, `0` for a blank.
+fn sudoku_grids(rng: &mut Rng, count: usize) -> String {
+    let mut s = String::new();
+    for i in 0..count {
+        let _ = writeln!(s, "Grid {:02}", i + 1);
+        for row in sudoku_puzzle(rng).chunks(9) {
+            let line = row.iter().map(|d| d.to_string()).collect::<String>();
+            let _ = writeln!(s, "{}", line);
+        }
+    }
+    s
+}
+
+/// Blanks out cells of a random solved grid as long as the solution stays
+/// unique, up to 55 cells.
+fn sudoku_puzzle(rng: &mut Rng) -> Vec<u8> {
+    let mut digits = (1..10).collect::<Vec<u8>>();
+    rng.shuffle(&mut digits);
+    let mut rows = shuffled_lines(rng);
+    let cols = shuffled_lines(rng);
+    let mut grid = vec![0; 81];
+    for (r, &row) in rows.iter().enumerate() {
+        for (c, &col) in cols.iter().enumerate() {
+            grid[r * 9 + c] = digits[(row * 3 + row / 3 + col) % 9];
+        }
+    }
+
+    rows = (0..81).collect();
+    rng.shuffle(&mut rows);
+    let mut blanks = 0;
+    for &cell in &rows {
+        if blanks == 55 {
+            break;
+        }
+        let digit = grid[cell];
+        grid[cell] = 0;
+        if count_solutions(&mut grid, 2) == 1 {
+            blanks += 1;
+        } else {
+            grid[cell] = digit;
+        }
+    }
+    grid
+}
+
+/// Returns the rows (or columns) of a grid in an order that keeps a solved
+/// grid solved: bands shuffled, and rows shuffled within each band.
+fn shuffled_lines(rng: &mut Rng) -> Vec<usize> {
+    let mut bands = vec![0, 1, 2];
+    rng.shuffle(&mut bands);
+    let mut lines = vec![];
+    for band in bands {
+        let mut band_lines = vec![band * 3, band * 3 + 1, band * 3 + 2];
+        rng.shuffle(&mut band_lines);
+        lines.extend(band_lines);
+    }
+    lines
+}
+
+/// Counts the solutions of `grid`, stopping at `limit`.
+fn count_solutions(grid: &mut [u8], limit: usize) -> usize {
+    // Fill the blank with the fewest candidates first.
+    let mut best: Option<(usize, u16)> = None;
+    for cell in (0..81).filter(|&i| grid[i] == 0) {
+        let candidates = candidates(grid, cell);
+        if best.map_or(true, |(_, b)| candidates.count_ones() < b.count_ones()) {
+            best = Some((cell, candidates));
+        }
+    }
+    let (cell, candidates) = match best {
+        Some(best) => best,
+        None => return 1,
+    };
+    let mut count = 0;
+    for digit in (1..10).filter(|&d| candidates & (1 << d) != 0) {
+        grid[cell] = digit;
+        count += count_solutions(grid, limit - count);
+        grid[cell] = 0;
+        if count >= limit {
+            break;
+        }
+    }
+    count
+}
+
+/// Returns the digits that may go into `cell`, as a bit set.
+fn candidates(grid: &[u8], cell: usize) -> u16 {
+    let (r, c) = (cell / 9, cell % 9);
+    let mut used = 0u16;
+    for i in 0..9 {
+        used |= 1 << grid[r * 9 + i];
+        used |= 1 << grid[i * 9 + c];
+        used |= 1 << grid[(r / 3 * 3 + i / 3) * 9 + c / 3 * 3 + i % 3];
+    }
+    !used & 0x3fe
+}
+
+/// `base,exponent` per line.
+fn base_exps(rng: &mut Rng, count: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..count {
+        let _ = writeln!(s, "{},{}", rng.range(2, 1_000_000), rng.range(1, 1_000_000));
+    }
+    s
+}
+
+/// Comma separated `x1,y1,x2,y2,x3,y3` of a triangle per line.
+fn triangles(rng: &mut Rng, count: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..count {
+        let coords = (0..6)
+            .map(|_| (rng.range(0, 1999) as i64 - 999).to_string())
+            .collect::<Vec<_>>();
+        let _ = writeln!(s, "{}", coords.join(","));
+    }
+    s
+}
+
+/// Sets of 7 to 12 distinct numbers, comma separated, per line.
+fn sets(rng: &mut Rng, count: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..count {
+        let len = rng.range(7, 13) as usize;
+        let mut set = vec![];
+        while set.len() < len {
+            let n = rng.range(1, 1000);
+            if !set.contains(&n) {
+                set.push(n);
+            }
+        }
+        let set = set.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        let _ = writeln!(s, "{}", set.join(","));
+    }
+    s
+}
+
+/// A connected network of `n` vertices as a symmetric matrix of edge
+/// weights, `-` where there is no edge.
+fn network(rng: &mut Rng, n: usize) -> String {
+    let mut weights = vec![vec![None; n]; n];
+    for i in 1..n {
+        // A random tree, joining each vertex to one before it, keeps the
+        // network connected.
+        let parent = rng.range(0, i as u64) as usize;
+        for j in 0..i {
+            if j == parent || rng.ratio(1, 3) {
+                let w = Some(rng.range(1, 1000));
+                weights[i][j] = w;
+                weights[j][i] = w;
+            }
+        }
+    }
+    let mut s = String::new();
+    for row in &weights {
+        let row = row
+            .iter()
+            .map(|w| w.map_or("-".to_string(), |w| w.to_string()))
+            .collect::<Vec<_>>();
+        let _ = writeln!(s, "{}", row.join(","));
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_solutions, generate, generated_files};
+
+    #[test]
+    fn all_files() {
+        for file_name in generated_files() {
+            let input = generate(file_name, Some(3), 1).unwrap();
+            assert!(!input.is_empty(), "{}", file_name);
+            assert_eq!(Some(input), generate(file_name, Some(3), 1));
+            assert!(generate(file_name, Some(3), 2) != generate(file_name, Some(3), 1));
+        }
+        assert_eq!(None, generate("p000_none.txt", None, 1));
+    }
+
+    #[test]
+    fn poker() {
+        let input = generate("p054_poker.txt", Some(20), 7).unwrap();
+        for line in input.lines() {
+            let mut cards = line.split(' ').collect::<Vec<_>>();
+            cards.sort();
+            cards.dedup();
+            assert_eq!(10, cards.len());
+        }
+    }
+
+    #[test]
+    fn sudoku() {
+        let input = generate("p096_sudoku.txt", Some(2), 7).unwrap();
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(20, lines.len());
+        assert_eq!("Grid 02", lines[10]);
+        let mut grid = lines[1..10]
+            .iter()
+            .flat_map(|l| l.bytes().map(|b| b - b'0'))
+            .collect::<Vec<_>>();
+        assert_eq!(81, grid.len());
+        assert!(grid.iter().filter(|&&d| d == 0).count() > 40);
+        assert_eq!(1, count_solutions(&mut grid, 2));
+    }
+
+    /// Parses a network made by `gen` into its matrix of weights.
+    fn network_matrix(size: usize, seed: u64) -> Vec<Vec<String>> {
+        let input = generate("p107_network.txt", Some(size), seed).unwrap();
+        input
+            .lines()
+            .map(|l| l.split(',').map(|w| w.to_string()).collect())
+            .collect()
+    }
+
+    /// Returns the number of vertices reached from the first one.
+    fn reached(matrix: &[Vec<String>]) -> usize {
+        let mut reached = vec![0];
+        let mut i = 0;
+        while i < reached.len() {
+            let v = reached[i];
+            for j in 0..matrix.len() {
+                if matrix[v][j] != "-" && !reached.contains(&j) {
+                    reached.push(j);
+                }
+            }
+            i += 1;
+        }
+        reached.len()
+    }
+
+    #[test]
+    fn network() {
+        let matrix = network_matrix(30, 7);
+        assert_eq!(30, matrix.len());
+        for i in 0..30 {
+            assert_eq!("-", matrix[i][i]);
+            for j in 0..30 {
+                assert_eq!(matrix[i][j], matrix[j][i]);
+            }
+        }
+        assert_eq!(30, reached(&matrix));
+    }
+
+    #[test]
+    fn network_connected() {
+        // Small networks have few random edges to make up for a missing
+        // tree edge.
+        for size in 2..8 {
+            for seed in 0..200 {
+                let matrix = network_matrix(size, seed);
+                assert_eq!(size, reached(&matrix), "size {} seed {}", size, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn roman() {
+        let input = generate("p089_roman.txt", Some(200), 7).unwrap();
+        assert!(input
+            .lines()
+            .any(|l| l.contains("IIII") || l.contains("VIIII")));
+        assert!(input
+            .lines()
+            .all(|l| !l.contains("VV") && !l.contains("IIIII")));
+    }
+}
@@ -0,0 +1,21 @@
+//! Reading the arguments of `problem!` in the solver sources, shared by
+//! `build.rs` and `hash_answers`, which both look at them without compiling
+//! them.
+
+/// Skips the metadata, `title: "..", difficulty: N, tags: [..],`, at the
+/// start of the arguments of `problem!`. Returns the rest with its leading whitespace
+/// trimmed, or `None` if the metadata is malformed.
+pub fn skip_meta(mut args: &str) -> Option<&str> {
+    for &(key, close) in &[("title:", '"'), ("difficulty:", ','), ("tags:", ']')] {
+        args = args.trim_start();
+        if !args.starts_with(key) {
+            continue;
+        }
+        // The title opens with a quote too.
+        let open = if close == '"' { args.find('"')? + 1 } else { 0 };
+        let end = open + args[open..].find(close)?;
+        let comma = end + args[end..].find(',')?;
+        args = &args[comma + 1..];
+    }
+    Some(args.trim_start())
+}
@@ -0,0 +1,256 @@
+//! Memory use of solver runs.
+//!
+//! The peak resident set size of a run is the high-water mark of the process
+//! after resetting it through `/proc/self/clear_refs` at the start of the
+//! run. Where that is not possible, it comes from `getrusage` and is the peak
+//! since the process started. Either way it covers the whole process, so it
+//! is per problem only if no other run overlaps. Building with the
+//! `count-alloc` feature installs `CountingAlloc` as the global allocator,
+//! which also counts the allocations of each run and their peak live size.
+
+use libc;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs::{File, OpenOptions};
+use std::io::prelude::*;
+use std::mem;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+const CLEAR_REFS: &'static str = "/proc/self/clear_refs";
+/// Writing this to `CLEAR_REFS` resets the peak RSS to the current RSS.
+const RESET_PEAK_RSS: &'static [u8] = b"5";
+const PROC_STATUS: &'static str = "/proc/self/status";
+const PEAK_RSS_FIELD: &'static str = "VmHWM:";
+
+#[cfg(target_os = "macos")]
+const MAXRSS_UNIT: u64 = 1;
+#[cfg(not(target_os = "macos"))]
+const MAXRSS_UNIT: u64 = 1024;
+
+/// Returns the peak resident set size of this process since it started, in
+/// bytes.
+pub fn peak_rss() -> Option<u64> {
+    let mut usage: libc::rusage = unsafe { mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+    Some(usage.ru_maxrss as u64 * MAXRSS_UNIT)
+}
+
+/// Resets the peak resident set size of this process to the current one.
+/// Returns false if the system does not allow it.
+fn reset_peak_rss() -> bool {
+    OpenOptions::new()
+        .write(true)
+        .open(CLEAR_REFS)
+        .and_then(|mut file| file.write_all(RESET_PEAK_RSS))
+        .is_ok()
+}
+
+/// Returns the peak resident set size of this process since it was last
+/// reset, in bytes.
+fn peak_rss_since_reset() -> Option<u64> {
+    let mut status = String::new();
+    let _ = File::open(PROC_STATUS)
+        .ok()?
+        .read_to_string(&mut status)
+        .ok()?;
+    let line = status.lines().find(|l| l.starts_with(PEAK_RSS_FIELD))?;
+    let kib = line[PEAK_RSS_FIELD.len()..]
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator that counts allocations on top of the system allocator.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        let _ = LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            let _ = LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    let _ = COUNT.fetch_add(1, Ordering::Relaxed);
+    let _ = BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    let mut peak = PEAK.load(Ordering::Relaxed);
+    while live > peak {
+        match PEAK.compare_exchange_weak(peak, live, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => break,
+            Err(p) => peak = p,
+        }
+    }
+}
+
+/// Allocations made during a run. Counts are process-wide, so they include
+/// other runs on concurrent threads.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub count: u64,
+    pub bytes: u64,
+    /// Peak size of the memory allocated during the run and still live.
+    pub peak: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Peak resident set size of the process during the run in bytes, or
+    /// since the process started if the peak could not be reset.
+    pub peak_rss: u64,
+    /// Present if the program was built with the `count-alloc` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<AllocStats>,
+}
+
+impl MemoryStats {
+    /// Returns the memory attributable to the run: the peak of its
+    /// allocations if they are counted, or the peak RSS otherwise.
+    pub fn peak(&self) -> u64 {
+        self.allocs.map_or(self.peak_rss, |a| a.peak)
+    }
+}
+
+/// Measures the memory use from its creation to `finish`.
+pub struct Tracker {
+    count: usize,
+    bytes: usize,
+    live: usize,
+    /// Whether the peak RSS was reset at the start.
+    reset: bool,
+}
+
+impl Tracker {
+    pub fn start() -> Tracker {
+        let live = LIVE.load(Ordering::Relaxed);
+        PEAK.store(live, Ordering::Relaxed);
+        Tracker {
+            count: COUNT.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+            live: live,
+            reset: reset_peak_rss(),
+        }
+    }
+
+    pub fn finish(self) -> Option<MemoryStats> {
+        let allocs = if INSTALLED.load(Ordering::Relaxed) {
+            Some(AllocStats {
+                count: (COUNT.load(Ordering::Relaxed) - self.count) as u64,
+                bytes: (BYTES.load(Ordering::Relaxed) - self.bytes) as u64,
+                peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.live) as u64,
+            })
+        } else {
+            None
+        };
+        let rss = if self.reset {
+            peak_rss_since_reset()
+        } else {
+            None
+        };
+        rss.or_else(peak_rss).map(|rss| MemoryStats {
+            peak_rss: rss,
+            allocs: allocs,
+        })
+    }
+}
+
+/// Formats a size in bytes for pretty output.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&'static str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytes, reset_peak_rss, Tracker};
+    use std::env;
+    use std::process::Command;
+
+    const SIZE: usize = 32 * 1024 * 1024;
+    /// Set in the process that runs `rss_alone` by itself.
+    const ALONE_ENV: &'static str = "EULER_MEMORY_TEST_ALONE";
+
+    #[test]
+    fn rss() {
+        // Tests solving problems reset the peak of the process, so it is
+        // measured in a process running that test alone.
+        let output = Command::new(env::current_exe().unwrap())
+            .args(&["memory::tests::rss_alone", "--exact", "--test-threads=1"])
+            .env(ALONE_ENV, "1")
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+
+    #[test]
+    fn rss_alone() {
+        if env::var_os(ALONE_ENV).is_none() {
+            return;
+        }
+        let base = Tracker::start().finish().unwrap().peak_rss;
+        assert!(base > 0);
+
+        let tracker = Tracker::start();
+        let v = vec![1u8; SIZE];
+        assert_eq!(SIZE, v.iter().map(|&b| b as usize).sum::<usize>());
+        assert!(tracker.finish().unwrap().peak_rss >= base + SIZE as u64 / 2);
+        drop(v);
+
+        // The peak of the vector is not blamed on later runs.
+        let peak = Tracker::start().finish().unwrap().peak_rss;
+        if reset_peak_rss() {
+            assert!(peak < base + SIZE as u64 / 2);
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("64.0 MiB", format_bytes(64 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,182 @@
+//! Named solver parameters, such as problem sizes.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+use {Error, Result};
+
+#[derive(Debug, Clone)]
+struct Param {
+    name: &'static str,
+    default: String,
+    value: Option<String>,
+    /// Smallest valid value, if there is one.
+    min: Option<String>,
+    /// Returns true if the string is a valid value.
+    check: fn(&str) -> bool,
+    /// Compares two values as the parameter's type, so that e.g. `0200` is
+    /// the same as `200`.
+    compare: fn(&str, &str) -> Option<Ordering>,
+}
+
+fn parses<T: FromStr>(s: &str) -> bool {
+    s.parse::<T>().is_ok()
+}
+
+fn compare<T: FromStr + PartialOrd>(a: &str, b: &str) -> Option<Ordering> {
+    a.parse::<T>().ok()?.partial_cmp(&b.parse::<T>().ok()?)
+}
+
+/// Parameters declared by a solver, set from the command line as
+/// `--param name=value`.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    params: Vec<Param>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Default::default()
+    }
+
+    /// Declares a parameter of type `T` with its default value.
+    pub fn define<T: FromStr + ToString + PartialOrd>(
+        mut self,
+        name: &'static str,
+        default: T,
+    ) -> Params {
+        self.params.push(Param {
+            name: name,
+            default: default.to_string(),
+            value: None,
+            min: None,
+            check: parses::<T>,
+            compare: compare::<T>,
+        });
+        self
+    }
+
+    /// Makes the parameter declared last reject values less than `min`.
+    ///
+    /// Panics if no parameter is declared.
+    pub fn at_least<T: ToString>(mut self, min: T) -> Params {
+        let param = self.params.last_mut().expect("no parameter declared");
+        param.min = Some(min.to_string());
+        self
+    }
+
+    /// Sets a parameter from `name=value`.
+    pub fn set(&mut self, assignment: &str) -> Result<()> {
+        let (name, value) = match assignment.find('=') {
+            Some(i) => (&assignment[..i], &assignment[i + 1..]),
+            None => {
+                let msg = format!("expected NAME=VALUE: {}", assignment);
+                return Err(Error::BadCli(msg));
+            }
+        };
+        let param = match self.params.iter_mut().find(|p| p.name == name) {
+            Some(param) => param,
+            None => return Err(Error::BadCli(format!("unknown parameter: {}", name))),
+        };
+        if !(param.check)(value) {
+            let msg = format!("invalid value for {}: {}", name, value);
+            return Err(Error::BadCli(msg));
+        }
+        if let Some(ref min) = param.min {
+            if (param.compare)(value, min) == Some(Ordering::Less) {
+                let msg = format!(
+                    "invalid value for {}: {} (must be at least {})",
+                    name, value, min
+                );
+                return Err(Error::BadCli(msg));
+            }
+        }
+        param.value = Some(value.to_string());
+        Ok(())
+    }
+
+    /// Returns the value of the parameter `name`.
+    ///
+    /// Panics if `name` is not declared or not of type `T`.
+    pub fn get<T: FromStr>(&self, name: &str) -> T {
+        let param = match self.params.iter().find(|p| p.name == name) {
+            Some(param) => param,
+            None => panic!("undeclared parameter: {}", name),
+        };
+        let value = param.value.as_ref().unwrap_or(&param.default);
+        match value.parse() {
+            Ok(value) => value,
+            Err(_) => panic!("parameter {} is not of the requested type", name),
+        }
+    }
+
+    /// Returns true if every parameter has its default value, which is the
+    /// only case the expected answer is for.
+    pub fn is_default(&self) -> bool {
+        self.params.iter().all(|p| {
+            p.value.as_ref().map_or(true, |v| {
+                (p.compare)(v, &p.default) == Some(Ordering::Equal)
+            })
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the names and default values of the parameters.
+    pub fn defaults(&self) -> Vec<(&'static str, &str)> {
+        self.params
+            .iter()
+            .map(|p| (p.name, p.default.as_str()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Params;
+
+    #[test]
+    fn get_and_set() {
+        let mut params = Params::new()
+            .define("limit", 200u32)
+            .define("name", "x".to_string());
+        assert!(params.is_default());
+        assert_eq!(200, params.get::<u32>("limit"));
+        assert_eq!("x", params.get::<String>("name"));
+
+        params.set("limit=1000").unwrap();
+        assert_eq!(1000, params.get::<u32>("limit"));
+        assert!(!params.is_default());
+
+        params.set("limit=200").unwrap();
+        assert!(params.is_default());
+        params.set("limit=0200").unwrap();
+        assert!(params.is_default());
+    }
+
+    #[test]
+    fn invalid() {
+        let mut params = Params::new().define("limit", 200u32);
+        assert!(params.set("limit").is_err());
+        assert!(params.set("size=1").is_err());
+        assert!(params.set("limit=-1").is_err());
+        assert_eq!(200, params.get::<u32>("limit"));
+    }
+
+    #[test]
+    fn at_least() {
+        let mut params = Params::new().define("divisor", 7i32).at_least(1);
+        assert!(params.set("divisor=0").is_err());
+        assert!(params.set("divisor=-3").is_err());
+        assert_eq!(7, params.get::<i32>("divisor"));
+        params.set("divisor=1").unwrap();
+        assert_eq!(1, params.get::<i32>("divisor"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn undeclared() {
+        let _ = Params::new().get::<u32>("limit");
+    }
+}
@@ -0,0 +1,157 @@
+//! Named phases of a solver run, timed by `phase` guards.
+//!
+//! ```ignore
+//! let primes = {
+//!     let _phase = common::phase("sieve");
+//!     sieve(limit)
+//! };
+//! ```
+//!
+//! Phases nest, and the phases of the same name under the same parent are
+//! summed. They are only recorded once `enable_phases` has been called, for
+//! `--json` or `--verbose`, and a guard costs a single atomic load otherwise.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Phases entered and not left yet, innermost last, below the finished
+    /// top-level phases of the run.
+    static STACK: RefCell<Vec<Phase>> = RefCell::new(vec![Phase::new("")]);
+}
+
+/// Time spent in a phase of a solver run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Phase {
+    pub name: String,
+    /// Total time in nanoseconds.
+    pub time: u64,
+    /// Number of times the phase was entered.
+    pub count: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Phase>,
+}
+
+impl Phase {
+    fn new(name: &str) -> Phase {
+        Phase {
+            name: name.to_string(),
+            time: 0,
+            count: 0,
+            children: vec![],
+        }
+    }
+
+    /// Adds `phase` to the children, merging it with one of the same name.
+    fn add_child(&mut self, phase: Phase) {
+        match self.children.iter().position(|c| c.name == phase.name) {
+            Some(i) => {
+                let child = &mut self.children[i];
+                child.time += phase.time;
+                child.count += phase.count;
+                for grandchild in phase.children {
+                    child.add_child(grandchild);
+                }
+            }
+            None => self.children.push(phase),
+        }
+    }
+}
+
+/// Records phases from now on, in every thread of the process.
+pub fn enable_phases() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Leaves its phase when dropped.
+#[must_use]
+pub struct PhaseGuard {
+    start: Option<Instant>,
+}
+
+/// Enters the phase `name` until the returned guard is dropped.
+pub fn phase(name: &str) -> PhaseGuard {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return PhaseGuard { start: None };
+    }
+    STACK.with(|s| s.borrow_mut().push(Phase::new(name)));
+    PhaseGuard {
+        start: Some(Instant::now()),
+    }
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        let start = match self.start {
+            Some(start) => start,
+            None => return,
+        };
+        let elapsed = start.elapsed();
+        STACK.with(|s| {
+            let mut stack = s.borrow_mut();
+            // The bottom of the stack holds the top-level phases.
+            if stack.len() < 2 {
+                return;
+            }
+            let mut phase = stack.pop().unwrap();
+            phase.time = elapsed.as_secs() * 1_000_000_000 + elapsed.subsec_nanos() as u64;
+            phase.count = 1;
+            stack.last_mut().unwrap().add_child(phase);
+        });
+    }
+}
+
+/// Forgets the phases recorded so far on this thread.
+pub fn reset_phases() {
+    if ENABLED.load(Ordering::Relaxed) {
+        STACK.with(|s| *s.borrow_mut() = vec![Phase::new("")]);
+    }
+}
+
+/// Returns the top-level phases recorded on this thread since the last call
+/// to `reset_phases` or `take_phases`.
+pub fn take_phases() -> Vec<Phase> {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return vec![];
+    }
+    STACK.with(|s| {
+        let mut stack = s.borrow_mut();
+        stack.truncate(1);
+        let root = stack.pop().unwrap();
+        stack.push(Phase::new(""));
+        root.children
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{enable_phases, phase, reset_phases, take_phases};
+
+    #[test]
+    fn nested() {
+        enable_phases();
+        reset_phases();
+        {
+            let _sieve = phase("sieve");
+        }
+        for _ in 0..3 {
+            let _search = phase("search");
+            let _pairs = phase("pairs");
+        }
+
+        let phases = take_phases();
+        let names = phases.iter().map(|p| &p.name[..]).collect::<Vec<_>>();
+        assert_eq!(vec!["sieve", "search"], names);
+        assert_eq!(1, phases[0].count);
+        assert!(phases[0].children.is_empty());
+        assert_eq!(3, phases[1].count);
+        assert_eq!("pairs", phases[1].children[0].name);
+        assert_eq!(3, phases[1].children[0].count);
+        assert!(phases[1].children[0].time <= phases[1].time);
+
+        assert!(take_phases().is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+//! Results of past `euler` runs.
+
+use serde::{Deserialize, Deserializer};
+use serde_json;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::result;
+use {
+    new_salt, Answer, Error, ErrorInfo, ErrorKind, Expected, MemoryStats, Phase, Result, RunInfo,
+    Status,
+};
+
+/// Where `euler` keeps the latest result of every problem it has run.
+pub const LAST_RESULTS: &'static str = "./.cache/last-results.json";
+
+/// Result of one problem, as saved between runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub id: u32,
+    /// `None` if the solver could not be run at all.
+    pub status: Option<Status>,
+    pub time: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+    /// Phases timed with `phase`, if they were recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<Phase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    /// How the answer compared with the expected one, if it was solved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Expected>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_error"
+    )]
+    pub error: Option<ErrorInfo>,
+    /// Timestamps, host and revision of the run, if it was solved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<RunInfo>,
+}
+
+/// Errors were saved as bare messages before they had a kind.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedError {
+    Info(ErrorInfo),
+    Message(String),
+}
+
+fn deserialize_error<'de, D: Deserializer<'de>>(
+    d: D,
+) -> result::Result<Option<ErrorInfo>, D::Error> {
+    Ok(Option::<SavedError>::deserialize(d)?.map(|e| match e {
+        SavedError::Info(info) => info,
+        SavedError::Message(msg) => ErrorInfo::new(ErrorKind::Io, msg),
+    }))
+}
+
+impl Record {
+    /// Returns a record of problem `id` that was not run.
+    pub fn new(id: u32) -> Record {
+        Record {
+            id: id,
+            status: None,
+            time: 0,
+            memory: None,
+            phases: vec![],
+            answer: None,
+            expected: None,
+            error: None,
+            run: None,
+        }
+    }
+
+    /// Returns true if the problem passed. An answer that could not be
+    /// checked, as it was solved with other parameters, does not fail it.
+    pub fn is_ok(&self) -> bool {
+        self.status == Some(Status::Ok) || self.status == Some(Status::Unchecked)
+    }
+
+    /// Returns a copy with the answer replaced by a salted hash, so that
+    /// saved results do not spoil it.
+    fn with_hashed_answer(&self) -> Record {
+        let salt = new_salt(&format!("p{:03}", self.id));
+        Record {
+            answer: self.answer.as_ref().map(|answer| answer.hashed(&salt)),
+            ..self.clone()
+        }
+    }
+}
+
+/// Reads the records saved at `path`, or returns `None` if there are none.
+pub fn load_records<P: AsRef<Path>>(path: P) -> Result<Option<Vec<Record>>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(Error::Io(e)),
+    };
+    Ok(Some(serde_json::from_reader(BufReader::new(file))?))
+}
+
+/// Saves `records` at `path`, replacing older records of the same problems
+/// and keeping those of the others.
+pub fn update_records<P: AsRef<Path>>(path: P, records: &[Record]) -> Result<()> {
+    let path = path.as_ref();
+    let mut merged = BTreeMap::new();
+    for r in load_records(path)?.unwrap_or_default() {
+        let _ = merged.insert(r.id, r);
+    }
+    for r in records {
+        let _ = merged.insert(r.id, r.clone());
+    }
+    save_records(
+        path,
+        &merged.into_iter().map(|(_, r)| r).collect::<Vec<_>>(),
+    )
+}
+
+/// Saves `records` at `path`, replacing whatever was there. Answers are
+/// saved as salted hashes.
+pub fn save_records<P: AsRef<Path>>(path: P, records: &[Record]) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let records = records
+        .iter()
+        .map(Record::with_hashed_answer)
+        .collect::<Vec<_>>();
+    serde_json::to_writer_pretty(File::create(path)?, &records)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_records, update_records, Record};
+    use serde_json;
+    use std::{env, fs, process};
+    use {ErrorKind, Status};
+
+    fn record(id: u32, status: Option<Status>) -> Record {
+        Record {
+            status: status,
+            ..Record::new(id)
+        }
+    }
+
+    #[test]
+    fn update() {
+        let path = env::temp_dir().join(format!("euler-records-{}.json", process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert!(load_records(&path).unwrap().is_none());
+        update_records(&path, &[record(2, Some(Status::Ng)), record(1, None)]).unwrap();
+        let solved = Record {
+            answer: Some("12345678".into()),
+            ..record(2, Some(Status::Ok))
+        };
+        update_records(&path, &[solved]).unwrap();
+
+        let records = load_records(&path).unwrap().unwrap();
+        assert_eq!(
+            vec![(1, None), (2, Some(Status::Ok))],
+            records.iter().map(|r| (r.id, r.status)).collect::<Vec<_>>()
+        );
+        let answer = records[1].answer.as_ref().unwrap();
+        assert!(answer.is_hashed());
+        assert_eq!(Some(true), answer.same_as(&"12345678".into()));
+        assert!(!fs::read_to_string(&path).unwrap().contains("12345678"));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn legacy_error() {
+        let json = r#"{"id": 54, "status": null, "time": 0, "error": "no data"}"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        let error = record.error.unwrap();
+        assert_eq!(ErrorKind::Io, error.kind);
+        assert_eq!("no data", error.message);
+    }
+}
@@ -0,0 +1,668 @@
+//! Consolidated reports of an `euler` run for other tools to read.
+
+use record::Record;
+use schema::{self, Expected, Host, SCHEMA_VERSION};
+use serde_json;
+use std::collections::BTreeMap;
+use std::io::prelude::*;
+use std::str::FromStr;
+use {format_bytes, Answer, Error, ErrorInfo, MemoryStats, Phase, Result, Status};
+
+/// Output format of a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Junit,
+    Tap,
+    Csv,
+    Markdown,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Format> {
+        match s {
+            "junit" => Ok(Format::Junit),
+            "tap" => Ok(Format::Tap),
+            "csv" => Ok(Format::Csv),
+            "markdown" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::BadCli(format!("unknown report format: {}", s))),
+        }
+    }
+}
+
+/// One problem as it appears in a report.
+#[derive(Serialize)]
+struct Entry<'a> {
+    id: u32,
+    name: String,
+    #[serde(skip)]
+    state: Option<Status>,
+    status: &'static str,
+    /// Whether the problem passed: a right answer within its time budget,
+    /// or an unchecked one.
+    ok: bool,
+    /// Whether the answer matches the expected one, however long it took.
+    matched: bool,
+    /// Whether the answer is right but took longer than its time budget.
+    slow: bool,
+    time: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory: Option<MemoryStats>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    phases: &'a [Phase],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<&'a Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a ErrorInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    finished: Option<&'a str>,
+}
+
+impl<'a> Entry<'a> {
+    fn new(record: &'a Record, reveal: bool) -> Entry<'a> {
+        Entry {
+            id: record.id,
+            name: format!("p{:03}", record.id),
+            state: record.status,
+            status: record.status.map_or("ERROR", |st| st.label()),
+            ok: record.is_ok(),
+            matched: matched(record),
+            slow: record.status == Some(Status::Slow),
+            time: record.time,
+            memory: record.memory,
+            phases: &record.phases,
+            answer: if reveal { record.answer.as_ref() } else { None },
+            error: record.error.as_ref(),
+            started: record.run.as_ref().map(|r| r.started.as_str()),
+            finished: record.run.as_ref().map(|r| r.finished.as_str()),
+        }
+    }
+
+    fn secs(&self) -> String {
+        secs(self.time)
+    }
+
+    /// Whether the solver ran but its answer is wrong or late, which JUnit
+    /// calls a failure rather than an error.
+    fn is_failure(&self) -> bool {
+        self.state == Some(Status::Ng) || self.slow
+    }
+
+    /// Peak memory in bytes, or empty if unknown.
+    fn peak_text(&self) -> String {
+        self.memory
+            .map(|m| m.peak().to_string())
+            .unwrap_or_default()
+    }
+
+    fn answer_text(&self) -> String {
+        self.answer.map(|a| a.to_string()).unwrap_or_default()
+    }
+
+    fn error_text(&self) -> &str {
+        self.error.map_or("", |e| e.message.as_str())
+    }
+}
+
+/// Returns whether the answer of `record` matches the expected one. Records
+/// saved before `expected` was kept tell it only by their status.
+fn matched(record: &Record) -> bool {
+    match record.expected {
+        Some(expected) => expected == Expected::Matched,
+        None => record.status == Some(Status::Ok) || record.status == Some(Status::Slow),
+    }
+}
+
+fn secs(nsec: u64) -> String {
+    format!("{}.{:09}", nsec / 1_000_000_000, nsec % 1_000_000_000)
+}
+
+#[derive(Serialize)]
+struct Summary<'a> {
+    schema: u32,
+    host: Host,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    revision: Option<String>,
+    passed: usize,
+    failed: usize,
+    time: u64,
+    problems: Vec<Entry<'a>>,
+}
+
+/// Writes a report of `records` in `format`, leaving out the answers unless
+/// `reveal` is set.
+pub fn write_report<W: Write>(
+    out: &mut W,
+    format: Format,
+    records: &[Record],
+    reveal: bool,
+) -> Result<()> {
+    let entries = records
+        .iter()
+        .map(|r| Entry::new(r, reveal))
+        .collect::<Vec<_>>();
+    match format {
+        Format::Junit => write_junit(out, &entries)?,
+        Format::Tap => write_tap(out, &entries)?,
+        Format::Csv => write_csv(out, &entries)?,
+        Format::Markdown => write_markdown(out, &entries)?,
+        Format::Json => {
+            let passed = entries.iter().filter(|e| e.ok).count();
+            let summary = Summary {
+                schema: SCHEMA_VERSION,
+                host: Host::current(),
+                revision: schema::revision(),
+                passed: passed,
+                failed: entries.len() - passed,
+                time: entries.iter().map(|e| e.time).sum(),
+                problems: entries,
+            };
+            serde_json::to_writer_pretty(&mut *out, &summary)?;
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+const HTML_HEAD: &'static str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Project Euler solutions</title>
+<style>
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; border-bottom: 1px solid #ddd; }
+th { cursor: pointer; text-align: left; background: #f4f4f4; }
+td.num { text-align: right; font-family: monospace; }
+.ok { color: #2a7d2a; }
+.ng { color: #c0392b; }
+.slower { color: #c0392b; }
+.faster { color: #2a7d2a; }
+svg text { font: 10px monospace; }
+svg rect.ok { fill: #5cb85c; }
+svg rect.ng { fill: #d9534f; }
+</style>
+</head>
+<body>
+"#;
+
+/// Sorts the table by a column when its header is clicked, using the
+/// `data-key` of the cells.
+const HTML_SCRIPT: &'static str = r#"<script>
+Array.prototype.forEach.call(document.querySelectorAll("th"), function (th, i) {
+  th.addEventListener("click", function () {
+    var body = document.querySelector("tbody");
+    var rows = Array.prototype.slice.call(body.rows);
+    var asc = th.getAttribute("data-order") !== "asc";
+    th.setAttribute("data-order", asc ? "asc" : "desc");
+    rows.sort(function (a, b) {
+      var x = a.cells[i].getAttribute("data-key");
+      var y = b.cells[i].getAttribute("data-key");
+      var d = isNaN(x) || isNaN(y) ? x.localeCompare(y) : x - y;
+      return asc ? d : -d;
+    });
+    rows.forEach(function (row) { body.appendChild(row); });
+  });
+});
+</script>
+"#;
+
+const CHART_ROW_HEIGHT: usize = 14;
+const CHART_LABEL_WIDTH: usize = 40;
+const CHART_BAR_WIDTH: usize = 600;
+const CHART_TEXT_WIDTH: usize = 90;
+
+/// Writes a self-contained HTML page of `records`: a table that sorts by any
+/// column, a bar chart of the times and, if `baseline` is given, the change
+/// of each time against it. `crates` has the `pkg/` crates of each problem.
+/// Answers are left out.
+pub fn write_html<W: Write>(
+    out: &mut W,
+    records: &[Record],
+    crates: &BTreeMap<u32, &[&str]>,
+    baseline: Option<&[Record]>,
+) -> Result<()> {
+    let entries = records
+        .iter()
+        .map(|r| Entry::new(r, false))
+        .collect::<Vec<_>>();
+    let passed = entries.iter().filter(|e| e.ok).count();
+    let time = entries.iter().map(|e| e.time).sum::<u64>();
+    let baseline_times = baseline.map(|records| {
+        records
+            .iter()
+            .filter(|r| r.status.is_some())
+            .map(|r| (r.id, r.time))
+            .collect::<BTreeMap<_, _>>()
+    });
+
+    write!(out, "{}", HTML_HEAD)?;
+    writeln!(out, "<h1>Project Euler solutions</h1>")?;
+    writeln!(
+        out,
+        "<p>{} problems, {} passed, {} failed, {} s in total.</p>",
+        entries.len(),
+        passed,
+        entries.len() - passed,
+        secs(time)
+    )?;
+    write_chart(out, &entries)?;
+
+    writeln!(out, "<table>")?;
+    write!(
+        out,
+        "<thead><tr><th>Problem</th><th>Status</th><th>Time (s)</th>\
+         <th>Memory</th><th>Crates</th>"
+    )?;
+    if baseline_times.is_some() {
+        write!(out, "<th>Change</th>")?;
+    }
+    writeln!(out, "</tr></thead>")?;
+    writeln!(out, "<tbody>")?;
+    for e in &entries {
+        let class = if e.ok { "ok" } else { "ng" };
+        let crates = crates.get(&e.id).map_or(String::new(), |c| c.join(", "));
+        write!(
+            out,
+            r#"<tr><td data-key="{id}">{name}</td><td data-key="{status}" class="{class}">{status}</td><td data-key="{time}" class="num">{secs}</td><td data-key="{memory}" class="num">{memory_text}</td><td data-key="{crates}">{crates}</td>"#,
+            id = e.id,
+            name = e.name,
+            status = escape_xml(e.status),
+            class = class,
+            time = e.time,
+            secs = e.secs(),
+            memory = e.memory.map_or(-1, |m| m.peak() as i64),
+            memory_text = e.memory.map(|m| format_bytes(m.peak())).unwrap_or_default(),
+            crates = escape_xml(&crates)
+        )?;
+        if let Some(ref times) = baseline_times {
+            match times.get(&e.id) {
+                Some(&base) if base > 0 => {
+                    let change = (e.time as f64 - base as f64) / base as f64 * 100.0;
+                    let class = if change > 0.0 { "slower" } else { "faster" };
+                    write!(
+                        out,
+                        r#"<td data-key="{:.1}" class="num {}">{:+.1}%</td>"#,
+                        change, class, change
+                    )?;
+                }
+                _ => write!(out, r#"<td data-key="" class="num">-</td>"#)?,
+            }
+        }
+        writeln!(out, "</tr>")?;
+    }
+    writeln!(out, "</tbody>")?;
+    writeln!(out, "</table>")?;
+    write!(out, "{}", HTML_SCRIPT)?;
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+    Ok(())
+}
+
+/// Writes an SVG bar chart of the times of `entries`, one row each.
+fn write_chart<W: Write>(out: &mut W, entries: &[Entry]) -> Result<()> {
+    let max = entries.iter().map(|e| e.time).max().unwrap_or(0).max(1);
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}">"#,
+        CHART_LABEL_WIDTH + CHART_BAR_WIDTH + CHART_TEXT_WIDTH,
+        entries.len() * CHART_ROW_HEIGHT
+    )?;
+    for (i, e) in entries.iter().enumerate() {
+        let y = i * CHART_ROW_HEIGHT;
+        let width = (e.time as f64 / max as f64 * CHART_BAR_WIDTH as f64).ceil() as usize;
+        writeln!(
+            out,
+            r#"<text x="0" y="{text_y}">{name}</text><rect x="{x}" y="{y}" width="{width}" height="{height}" class="{class}"><title>{name} {secs} s</title></rect><text x="{text_x}" y="{text_y}">{secs}</text>"#,
+            name = e.name,
+            x = CHART_LABEL_WIDTH,
+            y = y + 2,
+            width = width,
+            height = CHART_ROW_HEIGHT - 4,
+            class = if e.ok { "ok" } else { "ng" },
+            secs = e.secs(),
+            text_x = CHART_LABEL_WIDTH + width + 4,
+            text_y = y + CHART_ROW_HEIGHT - 3
+        )?;
+    }
+    writeln!(out, "</svg>")?;
+    Ok(())
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn write_junit<W: Write>(out: &mut W, entries: &[Entry]) -> Result<()> {
+    let failures = entries.iter().filter(|e| e.is_failure()).count();
+    let errors = entries.iter().filter(|e| !e.ok && !e.is_failure()).count();
+    let time = entries.iter().map(|e| e.time).sum::<u64>();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuite name="euler" tests="{}" failures="{}" errors="{}" time="{}">"#,
+        entries.len(),
+        failures,
+        errors,
+        secs(time)
+    )?;
+    for e in entries {
+        write!(
+            out,
+            r#"  <testcase classname="euler" name="{}" time="{}""#,
+            e.name,
+            e.secs()
+        )?;
+        if e.ok {
+            writeln!(out, "/>")?;
+            continue;
+        }
+        writeln!(out, ">")?;
+        match e.error {
+            _ if e.slow => writeln!(
+                out,
+                r#"    <failure type="slow" message="{}">over the time budget</failure>"#,
+                e.status
+            )?,
+            // The answer is left out unless it is revealed.
+            _ if e.is_failure() && e.answer.is_none() => writeln!(
+                out,
+                r#"    <failure type="wrong_answer" message="{}"/>"#,
+                e.status
+            )?,
+            _ if e.is_failure() => writeln!(
+                out,
+                r#"    <failure type="wrong_answer" message="{}">answer: {}</failure>"#,
+                e.status,
+                escape_xml(&e.answer_text())
+            )?,
+            Some(error) => writeln!(
+                out,
+                r#"    <error type="{}" message="{}"/>"#,
+                serde_json::to_value(error.kind)?.as_str().unwrap_or(""),
+                escape_xml(&error.message)
+            )?,
+            None => writeln!(out, r#"    <error message="{}"/>"#, e.status)?,
+        }
+        writeln!(out, "  </testcase>")?;
+    }
+    writeln!(out, "</testsuite>")?;
+    Ok(())
+}
+
+fn write_tap<W: Write>(out: &mut W, entries: &[Entry]) -> Result<()> {
+    writeln!(out, "TAP version 13")?;
+    writeln!(out, "1..{}", entries.len())?;
+    for (i, e) in entries.iter().enumerate() {
+        let ok = if e.ok { "ok" } else { "not ok" };
+        writeln!(out, "{} {} - {}", ok, i + 1, e.name)?;
+        writeln!(out, "  ---")?;
+        writeln!(out, "  status: {}", e.status)?;
+        writeln!(out, "  matched: {}", e.matched)?;
+        writeln!(out, "  time: {}", e.secs())?;
+        if let Some(memory) = e.memory {
+            writeln!(out, "  memory: {}", memory.peak())?;
+        }
+        if let Some(answer) = e.answer {
+            writeln!(out, "  answer: {}", serde_json::to_string(answer)?)?;
+        }
+        if let Some(error) = e.error {
+            writeln!(out, "  error: {}", serde_json::to_string(&error.message)?)?;
+        }
+        writeln!(out, "  ...")?;
+    }
+    Ok(())
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn write_csv<W: Write>(out: &mut W, entries: &[Entry]) -> Result<()> {
+    writeln!(out, "id,name,status,ok,matched,time,memory,answer,error")?;
+    for e in entries {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{}",
+            e.id,
+            e.name,
+            e.status,
+            e.ok,
+            e.matched,
+            e.secs(),
+            e.peak_text(),
+            escape_csv(&e.answer_text()),
+            escape_csv(e.error_text())
+        )?;
+    }
+    Ok(())
+}
+
+fn escape_markdown(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+fn write_markdown<W: Write>(out: &mut W, entries: &[Entry]) -> Result<()> {
+    writeln!(
+        out,
+        "| Problem | Status | Time (s) | Memory | Answer | Error |"
+    )?;
+    writeln!(
+        out,
+        "|---------|--------|---------:|-------:|--------|-------|"
+    )?;
+    for e in entries {
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} |",
+            e.name,
+            e.status,
+            e.secs(),
+            e.memory.map(|m| format_bytes(m.peak())).unwrap_or_default(),
+            escape_markdown(&e.answer_text()),
+            escape_markdown(e.error_text())
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_csv, escape_xml, write_html, write_report, Format};
+    use record::Record;
+    use std::collections::BTreeMap;
+    use {Answer, ErrorInfo, ErrorKind, Expected, MemoryStats, Status};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                status: Some(Status::Ok),
+                time: 1_500_000_000,
+                memory: Some(MemoryStats {
+                    peak_rss: 2_097_152,
+                    allocs: None,
+                }),
+                answer: Some(Answer::new(&12345678).unwrap()),
+                ..Record::new(1)
+            },
+            Record {
+                status: Some(Status::Ng),
+                time: 20,
+                answer: Some("1,2".into()),
+                expected: Some(Expected::Mismatched),
+                error: Some(ErrorInfo::new(ErrorKind::WrongAnswer, "wrong answer")),
+                ..Record::new(2)
+            },
+            Record {
+                status: Some(Status::Slow),
+                time: 30,
+                answer: Some(Answer::new(&87654321).unwrap()),
+                expected: Some(Expected::Matched),
+                ..Record::new(14)
+            },
+            Record {
+                error: Some(ErrorInfo::new(
+                    ErrorKind::Download,
+                    "p054_poker.txt: <not found>",
+                )),
+                ..Record::new(54)
+            },
+        ]
+    }
+
+    fn report(format: Format) -> String {
+        let mut out = vec![];
+        write_report(&mut out, format, &records(), true).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn format_names() {
+        assert_eq!(Format::Junit, "junit".parse().unwrap());
+        assert_eq!(Format::Markdown, "markdown".parse().unwrap());
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!("a&lt;b&gt; &amp; &quot;c&quot;", escape_xml("a<b> & \"c\""));
+        assert_eq!("abc", escape_csv("abc"));
+        assert_eq!("\"a,\"\"b\"\"\"", escape_csv("a,\"b\""));
+    }
+
+    #[test]
+    fn junit() {
+        let report = report(Format::Junit);
+        assert!(report.contains(r#"tests="4" failures="2" errors="1" time="1.500000050""#));
+        assert!(report.contains(r#"<testcase classname="euler" name="p001" time="1.500000000"/>"#));
+        assert!(
+            report.contains(r#"<failure type="wrong_answer" message="NG">answer: 1,2</failure>"#)
+        );
+        assert!(report
+            .contains(r#"<failure type="slow" message="SLOW">over the time budget</failure>"#));
+        assert!(report
+            .contains(r#"<error type="download" message="p054_poker.txt: &lt;not found&gt;"/>"#));
+    }
+
+    #[test]
+    fn tap() {
+        let report = report(Format::Tap);
+        assert!(report.starts_with("TAP version 13\n1..4\nok 1 - p001\n"));
+        assert!(report.contains("not ok 2 - p002\n  ---\n  status: NG\n  matched: false\n"));
+        assert!(report.contains("  error: \"wrong answer\"\n"));
+        assert!(report.contains("not ok 3 - p014\n  ---\n  status: SLOW\n  matched: true\n"));
+        assert!(report.contains("  error: \"p054_poker.txt: <not found>\"\n"));
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            "id,name,status,ok,matched,time,memory,answer,error\n\
+             1,p001,OK,true,true,1.500000000,2097152,12345678,\n\
+             2,p002,NG,false,false,0.000000020,,\"1,2\",wrong answer\n\
+             14,p014,SLOW,false,true,0.000000030,,87654321,\n\
+             54,p054,ERROR,false,false,0.000000000,,,p054_poker.txt: <not found>\n",
+            report(Format::Csv)
+        );
+    }
+
+    #[test]
+    fn hidden_answers() {
+        let mut out = vec![];
+        write_report(&mut out, Format::Csv, &records(), false).unwrap();
+        let report = String::from_utf8(out).unwrap();
+        assert!(report.contains("1,p001,OK,true,true,1.500000000,2097152,,\n"));
+        assert!(!report.contains("12345678"));
+        assert!(!report.contains("87654321"));
+
+        let mut out = vec![];
+        write_report(&mut out, Format::Junit, &records(), false).unwrap();
+        let report = String::from_utf8(out).unwrap();
+        assert!(report.contains(r#"<failure type="wrong_answer" message="NG"/>"#));
+        assert!(!report.contains("answer:"));
+    }
+
+    #[test]
+    fn unchecked() {
+        let records = vec![Record {
+            status: Some(Status::Unchecked),
+            time: 10,
+            answer: Some(Answer::new(&1234).unwrap()),
+            ..Record::new(1)
+        }];
+        let report = |format| {
+            let mut out = vec![];
+            write_report(&mut out, format, &records, true).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        assert!(report(Format::Csv).contains("\n1,p001,UNCHECKED,true,false,0.000000010,,1234,\n"));
+        assert!(report(Format::Tap).contains("\nok 1 - p001\n  ---\n  status: UNCHECKED\n"));
+        let junit = report(Format::Junit);
+        assert!(junit.contains(r#"tests="1" failures="0" errors="0""#));
+        assert!(junit.contains(r#"<testcase classname="euler" name="p001" time="0.000000010"/>"#));
+        let json = report(Format::Json);
+        assert!(json.contains(r#""passed": 1,"#));
+        assert!(json.contains(r#""failed": 0,"#));
+    }
+
+    #[test]
+    fn json() {
+        let report = report(Format::Json);
+        assert!(report.starts_with("{\n  \"schema\": 2,\n  \"host\": {"));
+        assert!(report.contains(r#""passed": 1,"#));
+        assert!(report.contains(r#""failed": 3,"#));
+        assert!(report.contains(r#""matched": true,"#));
+        assert!(report.contains(r#""slow": true,"#));
+        assert!(report.contains(r#""status": "ERROR","#));
+        assert!(report.contains(r#""kind": "download","#));
+        assert!(report.contains(r#""answer": 12345678"#));
+        assert!(report.contains(r#""peak_rss": 2097152"#));
+        assert!(report.contains(r#""answer": "1,2""#));
+    }
+
+    #[test]
+    fn html() {
+        let mut crates = BTreeMap::new();
+        let _ = crates.insert(54, &["playing_card"][..]);
+        let mut baseline = records();
+        baseline[0].time = 1_000_000_000;
+
+        let mut out = vec![];
+        write_html(&mut out, &records(), &crates, Some(&baseline)).unwrap();
+        let html = String::from_utf8(out).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.ends_with("</html>\n"));
+        assert!(html.contains("<p>4 problems, 1 passed, 3 failed, 1.500000050 s in total.</p>"));
+        assert!(html.contains(r#"<rect x="40" y="2" width="600" height="10" class="ok">"#));
+        assert!(html.contains(r#"<td data-key="1500000000" class="num">1.500000000</td>"#));
+        assert!(html.contains(r#"<td data-key="2097152" class="num">2.0 MiB</td>"#));
+        assert!(html.contains(r#"<td data-key="playing_card">playing_card</td>"#));
+        assert!(html.contains(r#"<td data-key="50.0" class="num slower">+50.0%</td>"#));
+        assert!(html.contains(r#"<td data-key="" class="num">-</td>"#));
+        assert!(!html.contains("12345678"));
+        assert!(!html.contains(" src=") && !html.contains(" href="));
+    }
+}
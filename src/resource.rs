@@ -0,0 +1,702 @@
+//! Data files read by file-based problems.
+
+use reqwest::{self, StatusCode};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use std::{cmp, env, process, result, thread};
+use {Error, Result};
+
+const CACHE_DIR: &'static str = "./.cache";
+const RESOURCE_DIR_ENV: &'static str = "EULER_RESOURCE_DIR";
+const ALLOW_UNLISTED_ENV: &'static str = "EULER_ALLOW_UNLISTED";
+const RESOURCE_MANIFEST: &'static str = include_str!("../resources.sha256");
+
+/// Suffix of a file being written, which is renamed once it is complete.
+const PART_SUFFIX: &'static str = ".part";
+
+const BASE_URL: &'static str = "http://projecteuler.net/project/resources/";
+const HTTP_TIMEOUT_SECS: u64 = 30;
+
+lazy_static! {
+    /// Client of every fetcher for projecteuler.net, built once so that its
+    /// connection pool is shared.
+    static ref PROJECT_EULER_CLIENT: result::Result<reqwest::Client, String> =
+        build_client(Duration::from_secs(HTTP_TIMEOUT_SECS)).map_err(|e| e.to_string());
+
+    /// Unlisted files already warned about, so that each is reported once.
+    static ref WARNED_UNLISTED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// Numbers the temporary files of the downloads of this process.
+static PART_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Failure to fetch a data file.
+#[derive(Fail, Debug)]
+pub enum FetchError {
+    /// The source does not have the file.
+    #[fail(display = "{}: not found at {}", _0, _1)]
+    NotFound(String, String),
+    /// The server answered with a status other than success or not found.
+    #[fail(display = "{}: unexpected HTTP status {}", _0, _1)]
+    HttpStatus(String, StatusCode),
+    /// The server could not be reached or the transfer broke off.
+    #[fail(display = "{}: network failure: {}", _0, _1)]
+    Network(String, #[cause] io::Error),
+    /// The file could not be read from local storage.
+    #[fail(display = "{}: {}", _0, _1)]
+    Io(String, #[cause] io::Error),
+}
+
+impl FetchError {
+    /// Returns true if trying again may succeed.
+    pub fn is_transient(&self) -> bool {
+        match *self {
+            FetchError::Network(..) => true,
+            FetchError::HttpStatus(_, status) => status.is_server_error(),
+            FetchError::NotFound(..) | FetchError::Io(..) => false,
+        }
+    }
+}
+
+/// A source of data files.
+pub trait ResourceFetcher: Send + Sync {
+    /// Returns the content of `file_name`.
+    fn fetch(&self, file_name: &str) -> result::Result<Vec<u8>, FetchError>;
+}
+
+/// Exponential backoff between retries of a failed download.
+#[derive(Debug, Clone, Copy)]
+pub struct Backoff {
+    /// Number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry. Each following delay is doubled.
+    pub initial_delay: Duration,
+    /// Upper bound of a single delay.
+    pub max_delay: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Backoff {
+        Backoff {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+impl Backoff {
+    /// Returns the delay before retry number `retry`, counting from 0.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry).unwrap_or(u32::max_value());
+        match self.initial_delay.checked_mul(factor) {
+            Some(delay) => cmp::min(delay, self.max_delay),
+            None => self.max_delay,
+        }
+    }
+}
+
+/// Downloads files over HTTP.
+pub struct HttpFetcher {
+    base_url: String,
+    client: reqwest::Client,
+    backoff: Backoff,
+}
+
+fn build_client(timeout: Duration) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| Error::Io(io::Error::new(io::ErrorKind::Other, e)))
+}
+
+impl HttpFetcher {
+    /// Creates a fetcher that downloads `file_name` from `base_url` followed
+    /// by `file_name`. Each request is aborted after `timeout`.
+    pub fn new(base_url: &str, timeout: Duration, backoff: Backoff) -> Result<HttpFetcher> {
+        Ok(HttpFetcher {
+            base_url: base_url.to_string(),
+            client: build_client(timeout)?,
+            backoff: backoff,
+        })
+    }
+
+    /// Creates a fetcher for the resources hosted on projecteuler.net, sharing
+    /// its client with the other fetchers of the process.
+    pub fn project_euler() -> Result<HttpFetcher> {
+        let client = PROJECT_EULER_CLIENT
+            .clone()
+            .map_err(|msg| Error::Io(io::Error::new(io::ErrorKind::Other, msg)))?;
+        Ok(HttpFetcher {
+            base_url: BASE_URL.to_string(),
+            client: client,
+            backoff: Backoff::default(),
+        })
+    }
+
+    fn get(&self, file_name: &str) -> result::Result<Vec<u8>, FetchError> {
+        let url = format!("{}{}", self.base_url, file_name);
+        let network_error = |e: reqwest::Error| {
+            FetchError::Network(
+                file_name.to_string(),
+                io::Error::new(io::ErrorKind::Other, e),
+            )
+        };
+
+        let mut resp = self
+            .client
+            .get(url.as_str())
+            .send()
+            .map_err(network_error)?;
+        let status = resp.status();
+        if status == StatusCode::NotFound {
+            return Err(FetchError::NotFound(file_name.to_string(), url));
+        }
+        if !status.is_success() {
+            return Err(FetchError::HttpStatus(file_name.to_string(), status));
+        }
+
+        let mut body = vec![];
+        let _ = resp
+            .read_to_end(&mut body)
+            .map_err(|e| FetchError::Network(file_name.to_string(), e))?;
+        Ok(body)
+    }
+}
+
+impl ResourceFetcher for HttpFetcher {
+    fn fetch(&self, file_name: &str) -> result::Result<Vec<u8>, FetchError> {
+        let mut retry = 0;
+        loop {
+            let err = match self.get(file_name) {
+                Ok(body) => return Ok(body),
+                Err(err) => err,
+            };
+            if !err.is_transient() || retry + 1 >= self.backoff.max_attempts {
+                return Err(err);
+            }
+
+            let program = env::args().next().unwrap();
+            let _ = writeln!(&mut io::stderr(), "{}: {}, retrying", program, err);
+            thread::sleep(self.backoff.delay(retry));
+            retry += 1;
+        }
+    }
+}
+
+/// Reads files from a local directory.
+#[derive(Debug, Clone)]
+pub struct FileFetcher {
+    dir: PathBuf,
+}
+
+impl FileFetcher {
+    /// Creates a fetcher that reads files from `dir`.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> FileFetcher {
+        FileFetcher { dir: dir.into() }
+    }
+}
+
+impl ResourceFetcher for FileFetcher {
+    fn fetch(&self, file_name: &str) -> result::Result<Vec<u8>, FetchError> {
+        let path = self.dir.join(file_name);
+        let mut content = vec![];
+        match File::open(&path).and_then(|mut file| file.read_to_end(&mut content)) {
+            Ok(_) => Ok(content),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Err(FetchError::NotFound(
+                file_name.to_string(),
+                self.dir.display().to_string(),
+            )),
+            Err(e) => Err(FetchError::Io(file_name.to_string(), e)),
+        }
+    }
+}
+
+/// Serves files held in memory.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFetcher {
+    files: HashMap<String, Vec<u8>>,
+}
+
+impl MemoryFetcher {
+    /// Creates a fetcher without any files.
+    pub fn new() -> MemoryFetcher {
+        Default::default()
+    }
+
+    /// Adds a file, replacing any previous content.
+    pub fn insert<S: Into<String>, B: Into<Vec<u8>>>(&mut self, file_name: S, content: B) {
+        let _ = self.files.insert(file_name.into(), content.into());
+    }
+}
+
+impl ResourceFetcher for MemoryFetcher {
+    fn fetch(&self, file_name: &str) -> result::Result<Vec<u8>, FetchError> {
+        match self.files.get(file_name) {
+            Some(content) => Ok(content.clone()),
+            None => Err(FetchError::NotFound(
+                file_name.to_string(),
+                "memory".to_string(),
+            )),
+        }
+    }
+}
+
+/// State of a data file in the directory of `Resources`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileState {
+    /// Matches its digest in the resource manifest.
+    Verified,
+    /// Has no digest in the resource manifest.
+    Unlisted,
+    /// Differs from its digest in the resource manifest.
+    Mismatch,
+    /// Left behind by an interrupted download.
+    Partial,
+    /// Not in the directory.
+    Missing,
+}
+
+/// A data file in the directory of `Resources`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileInfo {
+    pub name: String,
+    pub size: Option<u64>,
+    pub digest: Option<String>,
+    pub state: FileState,
+}
+
+/// Data files kept in a local directory, fetched on first use.
+pub struct Resources {
+    dir: PathBuf,
+    fetcher: Box<dyn ResourceFetcher>,
+    /// Whether files without a digest in the manifest may be used.
+    allow_unlisted: bool,
+    /// Whether the manifest has any digest. Until it does, files without one
+    /// are used with a warning.
+    manifest_has_digests: bool,
+}
+
+impl Resources {
+    /// Keeps files in `dir`, fetching missing ones with `fetcher`. Files
+    /// without a digest are allowed if `EULER_ALLOW_UNLISTED` is set.
+    pub fn new<P, F>(dir: P, fetcher: F) -> Resources
+    where
+        P: Into<PathBuf>,
+        F: ResourceFetcher + 'static,
+    {
+        Resources {
+            dir: dir.into(),
+            fetcher: Box::new(fetcher),
+            allow_unlisted: env::var_os(ALLOW_UNLISTED_ENV).map_or(false, |v| !v.is_empty()),
+            manifest_has_digests: manifest_entries(RESOURCE_MANIFEST).next().is_some(),
+        }
+    }
+
+    /// Reads files from `dir` and never downloads.
+    pub fn offline<P: Into<PathBuf>>(dir: P) -> Resources {
+        let dir = dir.into();
+        Resources::new(dir.clone(), FileFetcher::new(dir))
+    }
+
+    /// Uses the directory named by `EULER_RESOURCE_DIR` if it is set, or
+    /// downloads from projecteuler.net into the cache otherwise.
+    pub fn from_env() -> Result<Resources> {
+        match env::var_os(RESOURCE_DIR_ENV) {
+            Some(dir) => Ok(Resources::offline(dir)),
+            None => Ok(Resources::new(CACHE_DIR, HttpFetcher::project_euler()?)),
+        }
+    }
+
+    /// Allows files without a digest in the resource manifest, which are
+    /// rejected by default.
+    pub fn set_allow_unlisted(&mut self, allow: bool) {
+        self.allow_unlisted = allow;
+    }
+
+    pub fn allows_unlisted(&self) -> bool {
+        self.allow_unlisted
+    }
+
+    /// Returns true if files without a digest are errors: the manifest has
+    /// digests and unlisted files are not allowed.
+    pub fn rejects_unlisted(&self) -> bool {
+        self.manifest_has_digests && !self.allow_unlisted
+    }
+
+    /// Opens a data file after checking it against the resource manifest.
+    pub fn open(&self, file_name: &str) -> Result<File> {
+        let path = self.dir.join(file_name);
+        if !path.is_file() {
+            let content = self.fetcher.fetch(file_name)?;
+            self.verify(file_name, &content)?;
+
+            // Write under a temporary name so an interrupted write never
+            // leaves a truncated file behind. The name is unique to this
+            // download, as other workers or processes may fetch the same file
+            // at the same time; each of them renames a complete copy.
+            fs::create_dir_all(&self.dir)?;
+            let part = self.dir.join(format!(
+                "{}.{}.{}{}",
+                file_name,
+                process::id(),
+                PART_COUNTER.fetch_add(1, Ordering::Relaxed),
+                PART_SUFFIX
+            ));
+            File::create(&part)?.write_all(&content)?;
+            fs::rename(&part, &path)?;
+        }
+
+        let mut file = File::open(&path)?;
+        let mut content = vec![];
+        let _ = file.read_to_end(&mut content)?;
+        self.verify(file_name, &content)?;
+        let _ = file.seek(SeekFrom::Start(0))?;
+        Ok(file)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the state of `file_name` without fetching it.
+    pub fn inspect(&self, file_name: &str) -> Result<FileInfo> {
+        let mut info = FileInfo {
+            name: file_name.to_string(),
+            size: None,
+            digest: None,
+            state: FileState::Missing,
+        };
+        let mut content = vec![];
+        match File::open(self.dir.join(file_name)) {
+            Ok(mut file) => {
+                let _ = file.read_to_end(&mut content)?;
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(info),
+            Err(e) => return Err(Error::Io(e)),
+        }
+
+        let digest = sha256_hex(&content);
+        info.state = if file_name.ends_with(PART_SUFFIX) {
+            FileState::Partial
+        } else {
+            match expected_digest(file_name) {
+                Some(expected) if expected.eq_ignore_ascii_case(&digest) => FileState::Verified,
+                Some(_) => FileState::Mismatch,
+                None => FileState::Unlisted,
+            }
+        };
+        info.size = Some(content.len() as u64);
+        info.digest = Some(digest);
+        Ok(info)
+    }
+
+    /// Returns the names of the files left behind by interrupted downloads.
+    pub fn partial_files(&self) -> Result<Vec<String>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(Error::Io(e)),
+        };
+        let mut names = vec![];
+        for entry in entries {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            if name.ends_with(PART_SUFFIX) {
+                names.push(name);
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Removes `file_name` from the directory.
+    pub fn remove(&self, file_name: &str) -> Result<()> {
+        fs::remove_file(self.dir.join(file_name))?;
+        Ok(())
+    }
+
+    fn verify(&self, file_name: &str, content: &[u8]) -> Result<()> {
+        let expected = match expected_digest(file_name) {
+            Some(digest) => digest,
+            None if self.rejects_unlisted() => return Err(Error::Unlisted(file_name.to_string())),
+            None => {
+                if !self.allow_unlisted {
+                    warn_unlisted(file_name);
+                }
+                return Ok(());
+            }
+        };
+
+        let actual = sha256_hex(content);
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(Error::ChecksumMismatch {
+                file_name: file_name.to_string(),
+                expected: expected.to_string(),
+                actual: actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Warns once per process that `file_name` is used without a digest.
+fn warn_unlisted(file_name: &str) {
+    if WARNED_UNLISTED
+        .lock()
+        .unwrap()
+        .insert(file_name.to_string())
+    {
+        let _ = writeln!(
+            &mut io::stderr(),
+            "warning: {}: no digest in the resource manifest, used unchecked",
+            file_name
+        );
+    }
+}
+
+fn expected_digest(file_name: &str) -> Option<&'static str> {
+    manifest_entries(RESOURCE_MANIFEST)
+        .find(|&(_, name)| name == file_name)
+        .map(|(digest, _)| digest)
+}
+
+/// Returns the `(digest, file name)` entries of a manifest.
+fn manifest_entries<'a>(manifest: &'a str) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+    manifest
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some(digest), Some(name)) => Some((digest, name)),
+                _ => None,
+            }
+        })
+}
+
+pub fn sha256_hex(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Backoff, FetchError, FileState, HttpFetcher, MemoryFetcher, ResourceFetcher, Resources,
+    };
+    use std::env;
+    use std::fs;
+    use std::io::prelude::*;
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    use Error;
+
+    /// Serves one canned `(status, body)` response per connection on the
+    /// loopback interface and returns the base URL.
+    fn serve(responses: Vec<(u16, &'static str)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let _ = thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        format!("http://{}/", addr)
+    }
+
+    fn fetcher(base_url: &str) -> HttpFetcher {
+        let backoff = Backoff {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+        };
+        HttpFetcher::new(base_url, Duration::from_secs(5), backoff).unwrap()
+    }
+
+    #[test]
+    fn sha256_hex() {
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            super::sha256_hex(b"abc")
+        );
+    }
+
+    #[test]
+    fn verify_unlisted() {
+        let mut resources = Resources::new(env::temp_dir(), MemoryFetcher::new());
+        resources.manifest_has_digests = false;
+        assert!(resources.verify("unlisted.txt", b"abc").is_ok());
+
+        resources.manifest_has_digests = true;
+        match resources.verify("unlisted.txt", b"abc") {
+            Err(Error::Unlisted(ref name)) => assert_eq!("unlisted.txt", name),
+            r => panic!("unexpected result: {:?}", r),
+        }
+        resources.set_allow_unlisted(true);
+        assert!(resources.verify("unlisted.txt", b"abc").is_ok());
+    }
+
+    #[test]
+    fn backoff_delay() {
+        let backoff = Backoff {
+            max_attempts: 10,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+        assert_eq!(Duration::from_millis(100), backoff.delay(0));
+        assert_eq!(Duration::from_millis(200), backoff.delay(1));
+        assert_eq!(Duration::from_millis(800), backoff.delay(3));
+        assert_eq!(Duration::from_millis(1000), backoff.delay(4));
+        assert_eq!(Duration::from_millis(1000), backoff.delay(100));
+    }
+
+    #[test]
+    fn http_success() {
+        let url = serve(vec![(200, "1,2,3")]);
+        assert_eq!(b"1,2,3".to_vec(), fetcher(&url).fetch("a.txt").unwrap());
+    }
+
+    #[test]
+    fn http_retry_server_error() {
+        let url = serve(vec![(503, ""), (500, ""), (200, "ok")]);
+        assert_eq!(b"ok".to_vec(), fetcher(&url).fetch("a.txt").unwrap());
+    }
+
+    #[test]
+    fn http_retry_exhausted() {
+        let url = serve(vec![(503, ""), (503, ""), (503, "")]);
+        match fetcher(&url).fetch("a.txt") {
+            Err(FetchError::HttpStatus(_, status)) => assert_eq!(503, status.as_u16()),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn http_not_found() {
+        // Only one response is served, so a retry would fail differently.
+        let url = serve(vec![(404, "")]);
+        match fetcher(&url).fetch("a.txt") {
+            Err(FetchError::NotFound(..)) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn http_network_failure() {
+        let url = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/", listener.local_addr().unwrap())
+        };
+        match fetcher(&url).fetch("a.txt") {
+            Err(FetchError::Network(..)) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn resources_cache() {
+        let dir = env::temp_dir().join(format!("euler-resources-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut fetcher = MemoryFetcher::new();
+        fetcher.insert("a.txt", "abc");
+        let mut resources = Resources::new(dir.clone(), fetcher);
+        resources.manifest_has_digests = true;
+        assert!(resources.open("a.txt").is_err());
+        assert!(!dir.join("a.txt").exists());
+        resources.set_allow_unlisted(true);
+
+        let mut content = String::new();
+        let _ = resources
+            .open("a.txt")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!("abc", content);
+        assert!(dir.join("a.txt").is_file());
+        assert!(resources.open("b.txt").is_err());
+
+        let mut offline = Resources::offline(dir.clone());
+        offline.manifest_has_digests = true;
+        assert!(offline.open("a.txt").is_err());
+        offline.set_allow_unlisted(true);
+        assert!(offline.open("a.txt").is_ok());
+        assert!(offline.open("b.txt").is_err());
+
+        let info = resources.inspect("a.txt").unwrap();
+        assert_eq!(FileState::Unlisted, info.state);
+        assert_eq!(Some(3), info.size);
+        assert_eq!(
+            FileState::Missing,
+            resources.inspect("b.txt").unwrap().state
+        );
+
+        fs::write(dir.join("b.txt.part"), "ab").unwrap();
+        assert_eq!(vec!["b.txt.part"], resources.partial_files().unwrap());
+        assert_eq!(
+            FileState::Partial,
+            resources.inspect("b.txt.part").unwrap().state
+        );
+        resources.remove("b.txt.part").unwrap();
+        assert!(resources.partial_files().unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn concurrent_fetch() {
+        let dir = env::temp_dir().join(format!("euler-concurrent-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut fetcher = MemoryFetcher::new();
+        fetcher.insert("a.txt", vec![b'x'; 1 << 20]);
+        let mut resources = Resources::new(dir.clone(), fetcher);
+        resources.set_allow_unlisted(true);
+        let resources = Arc::new(resources);
+
+        let workers = (0..8)
+            .map(|_| {
+                let resources = resources.clone();
+                thread::spawn(move || {
+                    let mut content = vec![];
+                    let _ = resources
+                        .open("a.txt")
+                        .unwrap()
+                        .read_to_end(&mut content)
+                        .unwrap();
+                    content.len()
+                })
+            })
+            .collect::<Vec<_>>();
+        for worker in workers {
+            assert_eq!(1 << 20, worker.join().unwrap());
+        }
+        assert!(resources.partial_files().unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
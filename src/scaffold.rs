@@ -0,0 +1,118 @@
+//! Skeletons of new problem solvers, as generated by `euler new`.
+
+use UNKNOWN_ANSWER;
+
+const HEADER: &'static str = "#![warn(
+    bad_style,
+    unused,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results
+)]
+
+#[macro_use(problem)]
+extern crate common;
+";
+
+const PLAIN_SOLVER: &'static str = "fn compute(n: u64) -> u64 {
+    n
+}
+
+fn solve() -> u64 {
+    compute(0)
+}
+";
+
+const FILE_SOLVER: &'static str = "use std::fs::File;
+use std::io::prelude::*;
+use std::io::{self, BufReader};
+
+fn compute(lines: &[String]) -> usize {
+    lines.len()
+}
+
+fn solve(file: File) -> io::Result<usize> {
+    let lines = BufReader::new(file)
+        .lines()
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(compute(&lines))
+}
+";
+
+const PLAIN_TESTS: &'static str = "#[cfg(test)]
+mod tests {
+    #[test]
+    fn example() {
+        assert_eq!(0, super::compute(0));
+    }
+}
+";
+
+const FILE_TESTS: &'static str = "#[cfg(test)]
+mod tests {
+    #[test]
+    fn example() {
+        assert_eq!(0, super::compute(&[]));
+    }
+}
+";
+
+/// Returns the source of a solver for problem `id` that reads `file_name` if
+/// given. The expected answer is `UNKNOWN_ANSWER`, so that the solver runs
+/// unchecked until it is filled in.
+pub fn problem_source(id: u32, title: Option<&str>, file_name: Option<&str>) -> String {
+    let mut args = vec![];
+    if let Some(title) = title {
+        args.push(format!("title: {:?}", title));
+    }
+    args.push(format!("{:?}", UNKNOWN_ANSWER));
+    if let Some(file_name) = file_name {
+        args.push(format!("{:?}", file_name));
+    }
+    args.push("solve".to_string());
+
+    let (solver, tests) = match file_name {
+        Some(_) => (FILE_SOLVER, FILE_TESTS),
+        None => (PLAIN_SOLVER, PLAIN_TESTS),
+    };
+    format!(
+        "//! [Problem {id}](https://projecteuler.net/problem={id}) solver.\n\
+         \n\
+         {header}\n\
+         {solver}\n\
+         problem!(\n    {args}\n);\n\
+         \n\
+         {tests}",
+        id = id,
+        header = HEADER,
+        solver = solver,
+        args = args.join(",\n    "),
+        tests = tests
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::problem_source;
+
+    #[test]
+    fn plain() {
+        let source = problem_source(138, Some("Special \"Isosceles\""), None);
+        assert!(source.starts_with(
+            "//! [Problem 138](https://projecteuler.net/problem=138) solver.\n\n#![warn(\n"
+        ));
+        assert!(source.contains("\nfn solve() -> u64 {\n"));
+        assert!(source.contains(
+            "\nproblem!(\n    title: \"Special \\\"Isosceles\\\"\",\n    \"?\",\n    solve\n);\n"
+        ));
+        assert!(source.ends_with("    }\n}\n"));
+    }
+
+    #[test]
+    fn file() {
+        let source = problem_source(138, None, Some("p138_data.txt"));
+        assert!(source.contains("\nfn solve(file: File) -> io::Result<usize> {\n"));
+        assert!(source.contains("\nproblem!(\n    \"?\",\n    \"p138_data.txt\",\n    solve\n);\n"));
+    }
+}
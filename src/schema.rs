@@ -0,0 +1,170 @@
+//! Versions of the JSON results, and the host and build they were taken on.
+//!
+//! Version 1 results are `{time, answer, is_ok}` with the optional fields
+//! added since. Version 2 adds `schema`, the problem `id`, the `expected`
+//! answer status and a `run` object with timestamps, host and git revision,
+//! and the same header for the `error` printed when solving fails. Results of
+//! version 1 still load, as every added field is optional.
+
+use libc;
+use std::fs::File;
+use std::io::prelude::*;
+use time;
+
+/// Version of the results written by this build.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Version of the results saved without a `schema` field.
+pub fn legacy_schema() -> u32 {
+    1
+}
+
+const CPUINFO: &'static str = "/proc/cpuinfo";
+
+/// How the answer compared with the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Expected {
+    Matched,
+    Mismatched,
+    /// There is no expected answer for the input and parameters.
+    Unchecked,
+}
+
+/// The machine and build a result was taken on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Host {
+    /// CPU model, if the system tells.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<String>,
+    /// Number of online cores.
+    pub cores: u32,
+    /// `rustc --version` of the compiler that built the solver.
+    pub rustc: String,
+    /// Cargo profile of the build, `debug` or `release`.
+    pub profile: String,
+}
+
+lazy_static! {
+    /// The host this process runs on, read once as every result carries it.
+    static ref CURRENT_HOST: Host = Host {
+        cpu: cpu_model(),
+        cores: unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) }.max(1) as u32,
+        rustc: env!("EULER_RUSTC_VERSION").to_string(),
+        profile: env!("EULER_PROFILE").to_string(),
+    };
+}
+
+impl Host {
+    pub fn current() -> Host {
+        CURRENT_HOST.clone()
+    }
+}
+
+fn cpu_model() -> Option<String> {
+    let mut content = String::new();
+    let _ = File::open(CPUINFO)
+        .ok()?
+        .read_to_string(&mut content)
+        .ok()?;
+    content
+        .lines()
+        .filter(|line| line.starts_with("model name"))
+        .filter_map(|line| line.splitn(2, ':').nth(1))
+        .map(|model| model.trim().to_string())
+        .next()
+}
+
+/// When, where and from which revision a result was taken.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunInfo {
+    /// RFC 3339 time in UTC.
+    pub started: String,
+    pub finished: String,
+    pub host: Host,
+    /// Git revision the solver was built from, if it was built in a checkout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+}
+
+impl RunInfo {
+    /// Returns the information of a run from `started` until now.
+    pub fn since(started: String) -> RunInfo {
+        RunInfo {
+            started: started,
+            finished: timestamp(),
+            host: Host::current(),
+            revision: revision(),
+        }
+    }
+}
+
+/// Returns the current time in RFC 3339 format, in UTC.
+pub fn timestamp() -> String {
+    time::now_utc().rfc3339().to_string()
+}
+
+/// Returns the git revision this crate was built from, if known.
+pub fn revision() -> Option<String> {
+    match option_env!("EULER_GIT_REVISION") {
+        Some(rev) if !rev.is_empty() => Some(rev.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{timestamp, Expected, RunInfo, SCHEMA_VERSION};
+    use serde_json;
+    use {ErrorOutput, Hidden, SolverResult};
+
+    #[test]
+    fn run_info() {
+        let started = timestamp();
+        assert_eq!(20, started.len());
+        assert!(started.ends_with('Z'));
+
+        let info = RunInfo::since(started.clone());
+        assert!(info.started <= info.finished);
+        assert!(info.host.cores >= 1);
+        assert!(info.host.rustc.starts_with("rustc "));
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(info, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn legacy() {
+        let result: SolverResult<u64> =
+            serde_json::from_str(r#"{"time": 10, "answer": 42, "is_ok": true}"#).unwrap();
+        assert_eq!(1, result.schema);
+        assert_eq!(None, result.id);
+        assert_eq!(None, result.expected);
+        assert!(result.run.is_none());
+
+        let output: ErrorOutput =
+            serde_json::from_str(r#"{"error": {"kind": "io", "message": "no data"}}"#).unwrap();
+        assert_eq!(1, output.schema);
+    }
+
+    #[test]
+    fn current() {
+        let result = SolverResult {
+            schema: SCHEMA_VERSION,
+            id: Some(96),
+            expected: Some(Expected::Unchecked),
+            run: None,
+            time: 10,
+            answer: Hidden,
+            is_ok: true,
+            unchecked: true,
+            slow: false,
+            bench: None,
+            memory: None,
+            phases: vec![],
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.starts_with(r#"{"schema":2,"id":96,"expected":"unchecked","#));
+        let result: SolverResult<Option<String>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some(Expected::Unchecked), result.expected);
+    }
+}
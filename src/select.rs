@@ -0,0 +1,312 @@
+//! Selection of the problems `euler` runs.
+
+use record::Record;
+use {Error, Problem, Result};
+
+const NSEC_PER_SEC: f64 = 1e9;
+
+/// Parses a duration such as `1s`, `250ms` or `1.5` (seconds) into
+/// nanoseconds.
+pub fn parse_duration(s: &str) -> Result<u64> {
+    let units = [
+        ("ns", 1e-9),
+        ("us", 1e-6),
+        ("ms", 1e-3),
+        ("s", 1.0),
+        ("m", 60.0),
+    ];
+    let (num, scale) = units
+        .iter()
+        .find(|&&(unit, _)| s.ends_with(unit))
+        .map(|&(unit, scale)| (&s[..s.len() - unit.len()], scale))
+        .unwrap_or((s, 1.0));
+    let secs = match num.parse::<f64>() {
+        Ok(n) if n >= 0.0 => n * scale,
+        _ => return Err(Error::BadCli(format!("invalid duration: {}", s))),
+    };
+    Ok((secs * NSEC_PER_SEC).round() as u64)
+}
+
+/// Criteria for choosing problems. An empty selection chooses all of them.
+#[derive(Debug, Default)]
+pub struct Selection {
+    /// Inclusive id ranges; any of them may match.
+    ranges: Vec<(u32, u32)>,
+    /// Tags that must all be present.
+    tags: Vec<String>,
+    /// Inclusive range of difficulty ratings in percent.
+    difficulty: Option<(u32, u32)>,
+    /// Only problems whose last run took at least this many nanoseconds.
+    slower_than: Option<u64>,
+    /// Only problems whose last run did not succeed.
+    failed_only: bool,
+}
+
+impl Selection {
+    pub fn new() -> Selection {
+        Default::default()
+    }
+
+    /// Adds problems given as `54`, `p054` or a range `1-50`.
+    pub fn add_ids(&mut self, s: &str) -> Result<()> {
+        let parse = |id: &str| {
+            let id = if id.starts_with('p') { &id[1..] } else { id };
+            id.parse::<u32>()
+                .map_err(|_| Error::BadCli(format!("invalid problem selector: {}", s)))
+        };
+        let range = match s.find('-') {
+            Some(i) => (parse(&s[..i])?, parse(&s[i + 1..])?),
+            None => {
+                let id = parse(s)?;
+                (id, id)
+            }
+        };
+        if range.0 > range.1 {
+            return Err(Error::BadCli(format!("empty problem range: {}", s)));
+        }
+        self.ranges.push(range);
+        Ok(())
+    }
+
+    pub fn add_tag<S: Into<String>>(&mut self, tag: S) {
+        self.tags.push(tag.into());
+    }
+
+    /// Keeps problems rated `25` or in a range `5-20` percent. Problems
+    /// without a rating are dropped.
+    pub fn set_difficulty(&mut self, s: &str) -> Result<()> {
+        let parse = |rating: &str| {
+            rating
+                .trim_end_matches('%')
+                .parse::<u32>()
+                .map_err(|_| Error::BadCli(format!("invalid difficulty: {}", s)))
+        };
+        let range = match s.find('-') {
+            Some(i) => (parse(&s[..i])?, parse(&s[i + 1..])?),
+            None => {
+                let rating = parse(s)?;
+                (rating, rating)
+            }
+        };
+        if range.0 > range.1 {
+            return Err(Error::BadCli(format!("empty difficulty range: {}", s)));
+        }
+        self.difficulty = Some(range);
+        Ok(())
+    }
+
+    pub fn set_slower_than(&mut self, nsec: u64) {
+        self.slower_than = Some(nsec);
+    }
+
+    pub fn set_failed_only(&mut self) {
+        self.failed_only = true;
+    }
+
+    /// Returns true if selecting depends on the results of a previous run.
+    pub fn needs_records(&self) -> bool {
+        self.slower_than.is_some() || self.failed_only
+    }
+
+    /// Picks the selected problems. Problems without a record in `records`
+    /// are dropped by the filters that need one.
+    pub fn select(&self, problems: &[Problem], records: &[Record]) -> Result<Vec<Problem>> {
+        for tag in &self.tags {
+            if !problems.iter().any(|p| p.has_tag(tag)) {
+                return Err(Error::BadCli(format!("unknown tag: {}", tag)));
+            }
+        }
+
+        let selected = problems
+            .iter()
+            .filter(|p| self.matches_ids_and_tags(p))
+            .filter(|p| match (self.difficulty, p.meta.difficulty) {
+                (None, _) => true,
+                (Some((lo, hi)), Some(rating)) => lo <= rating && rating <= hi,
+                (Some(_), None) => false,
+            })
+            .filter(|p| {
+                if !self.needs_records() {
+                    return true;
+                }
+                let record = match records.iter().find(|r| r.id == p.id) {
+                    Some(record) => record,
+                    None => return false,
+                };
+                let slow = self.slower_than.map_or(true, |nsec| record.time >= nsec);
+                let failed = !self.failed_only || !record.is_ok();
+                slow && failed
+            })
+            .cloned()
+            .collect();
+        Ok(selected)
+    }
+
+    /// Returns the problems left out by the difficulty filter because they
+    /// have no rating.
+    pub fn unrated(&self, problems: &[Problem]) -> Vec<Problem> {
+        if self.difficulty.is_none() {
+            return vec![];
+        }
+        problems
+            .iter()
+            .filter(|p| p.meta.difficulty.is_none() && self.matches_ids_and_tags(p))
+            .cloned()
+            .collect()
+    }
+
+    fn matches_ids_and_tags(&self, p: &Problem) -> bool {
+        let in_range =
+            self.ranges.is_empty() || self.ranges.iter().any(|&(lo, hi)| lo <= p.id && p.id <= hi);
+        in_range && self.tags.iter().all(|tag| p.has_tag(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_duration, Selection};
+    use record::Record;
+    use {Meta, Problem, Solver, Status};
+
+    fn solver() -> Solver<'static> {
+        Solver::new("", String::new)
+    }
+
+    const PROBLEMS: &'static [Problem] = &[
+        Problem {
+            id: 1,
+            solver: solver,
+            meta: &Meta::NONE,
+            tags: &[],
+            crates: &[],
+        },
+        Problem {
+            id: 3,
+            solver: solver,
+            meta: &Meta {
+                title: "",
+                difficulty: Some(5),
+                tags: &["dp"],
+            },
+            tags: &["prime"],
+            crates: &["prime"],
+        },
+        Problem {
+            id: 22,
+            solver: solver,
+            meta: &Meta::NONE,
+            tags: &["file"],
+            crates: &[],
+        },
+        Problem {
+            id: 54,
+            solver: solver,
+            meta: &Meta {
+                title: "",
+                difficulty: Some(10),
+                tags: &[],
+            },
+            tags: &["file", "playing_card"],
+            crates: &["playing_card"],
+        },
+    ];
+
+    fn ids(sel: &Selection, records: &[Record]) -> Vec<u32> {
+        sel.select(PROBLEMS, records)
+            .unwrap()
+            .iter()
+            .map(|p| p.id)
+            .collect()
+    }
+
+    fn record(id: u32, status: Status, time: u64) -> Record {
+        Record {
+            status: Some(status),
+            time: time,
+            ..Record::new(id)
+        }
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(1_000_000_000, parse_duration("1s").unwrap());
+        assert_eq!(1_500_000_000, parse_duration("1.5").unwrap());
+        assert_eq!(250_000_000, parse_duration("250ms").unwrap());
+        assert_eq!(3_000, parse_duration("3us").unwrap());
+        assert_eq!(120_000_000_000, parse_duration("2m").unwrap());
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("1h").is_err());
+    }
+
+    #[test]
+    fn ids_and_ranges() {
+        let mut sel = Selection::new();
+        assert_eq!(vec![1, 3, 22, 54], ids(&sel, &[]));
+
+        sel.add_ids("1-3").unwrap();
+        sel.add_ids("p054").unwrap();
+        assert_eq!(vec![1, 3, 54], ids(&sel, &[]));
+
+        assert!(sel.add_ids("5-4").is_err());
+        assert!(sel.add_ids("x").is_err());
+        assert!(sel.add_ids("1-").is_err());
+    }
+
+    #[test]
+    fn tags() {
+        let mut sel = Selection::new();
+        sel.add_tag("file");
+        assert_eq!(vec![22, 54], ids(&sel, &[]));
+        sel.add_tag("playing_card");
+        assert_eq!(vec![54], ids(&sel, &[]));
+
+        let mut sel = Selection::new();
+        sel.add_tag("dp");
+        assert_eq!(vec![3], ids(&sel, &[]));
+
+        let mut sel = Selection::new();
+        sel.add_tag("no_such_tag");
+        assert!(sel.select(PROBLEMS, &[]).is_err());
+    }
+
+    #[test]
+    fn difficulty() {
+        let mut sel = Selection::new();
+        sel.set_difficulty("5").unwrap();
+        assert_eq!(vec![3], ids(&sel, &[]));
+        sel.set_difficulty("5-10%").unwrap();
+        assert_eq!(vec![3, 54], ids(&sel, &[]));
+        sel.set_difficulty("15-100").unwrap();
+        assert!(ids(&sel, &[]).is_empty());
+        let unrated = sel.unrated(PROBLEMS);
+        assert_eq!(
+            vec![1, 22],
+            unrated.iter().map(|p| p.id).collect::<Vec<_>>()
+        );
+        assert!(Selection::new().unrated(PROBLEMS).is_empty());
+
+        assert!(sel.set_difficulty("10-5").is_err());
+        assert!(sel.set_difficulty("hard").is_err());
+    }
+
+    #[test]
+    fn previous_results() {
+        let records = [
+            record(1, Status::Ok, 2_000),
+            record(3, Status::Ng, 10),
+            record(22, Status::Timeout, 5_000),
+        ];
+
+        let mut sel = Selection::new();
+        sel.set_slower_than(1_000);
+        assert_eq!(vec![1, 22], ids(&sel, &records));
+
+        let mut sel = Selection::new();
+        sel.set_failed_only();
+        assert_eq!(vec![3, 22], ids(&sel, &records));
+
+        sel.set_slower_than(1_000);
+        assert_eq!(vec![22], ids(&sel, &records));
+    }
+}
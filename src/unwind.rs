@@ -0,0 +1,125 @@
+//! Panics of solvers run in-process.
+//!
+//! `catch_panic` runs a solver under `catch_unwind` and returns the message
+//! and location of its panic, which the default hook would otherwise print in
+//! the middle of the output of the other problems.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use {ErrorInfo, ErrorKind};
+
+/// A panic caught by `catch_panic`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaughtPanic {
+    pub message: String,
+    /// `file:line:column` of the panic, if known.
+    pub location: Option<String>,
+}
+
+impl CaughtPanic {
+    pub fn info(&self) -> ErrorInfo {
+        ErrorInfo {
+            kind: ErrorKind::Panic,
+            message: self.message.clone(),
+            location: self.location.clone(),
+        }
+    }
+}
+
+impl fmt::Display for CaughtPanic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "panicked at '{}'", self.message)?;
+        if let Some(ref location) = self.location {
+            write!(f, ", {}", location)?;
+        }
+        Ok(())
+    }
+}
+
+static HOOK: Once = Once::new();
+
+thread_local! {
+    static CAPTURING: Cell<bool> = Cell::new(false);
+    static CAUGHT: RefCell<Option<CaughtPanic>> = RefCell::new(None);
+}
+
+/// Installs a panic hook that records the panics of threads inside
+/// `catch_panic` and leaves the others to the previous hook.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING.with(|c| c.get()) {
+                previous(info);
+                return;
+            }
+            let caught = CaughtPanic {
+                message: payload_message(info.payload()),
+                location: info
+                    .location()
+                    .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            };
+            CAUGHT.with(|c| *c.borrow_mut() = Some(caught));
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<Any>".to_string()
+    }
+}
+
+/// Calls `f`, returning its panic instead of unwinding further.
+pub fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, CaughtPanic> {
+    install_hook();
+    let was_capturing = CAPTURING.with(|c| c.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.with(|c| c.set(was_capturing));
+    result.map_err(|payload| {
+        CAUGHT
+            .with(|c| c.borrow_mut().take())
+            .unwrap_or_else(|| CaughtPanic {
+                message: payload_message(&*payload),
+                location: None,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catch_panic;
+    use ErrorKind;
+
+    #[test]
+    fn caught() {
+        assert_eq!(Ok(3), catch_panic(|| 1 + 2));
+
+        let line = line!() + 1;
+        let caught = catch_panic(|| -> u32 { panic!("no {} here", "answer") }).unwrap_err();
+        assert_eq!("no answer here", caught.message);
+        let location = caught.location.clone().unwrap();
+        assert!(location.starts_with(&format!("src/unwind.rs:{}:", line)));
+        assert_eq!(
+            format!("panicked at 'no answer here', {}", location),
+            caught.to_string()
+        );
+        assert_eq!(ErrorKind::Panic, caught.info().kind);
+    }
+
+    #[test]
+    fn nested() {
+        let outer = catch_panic(|| {
+            assert!(catch_panic(|| panic!("inner")).is_err());
+            panic!("outer")
+        });
+        assert_eq!("outer", outer.unwrap_err().message);
+    }
+}